@@ -122,6 +122,37 @@ pub type JSValueRef = *const OpaqueJSValue;
 /// A JavaScript object. A [`JSObjectRef`] is a [`JSValueRef`].
 pub type JSObjectRef = *mut OpaqueJSValue;
 
+/// The callback called when the execution time limit of a context group is
+/// reached, see [`JSContextGroupSetExecutionTimeLimit()`].
+///
+/// * `ctx`: The execution context running the script.
+/// * `context`: The pointer given to
+///   [`JSContextGroupSetExecutionTimeLimit()`].
+///
+/// Returns `true` to terminate the script, or `false` to let it run until
+/// the limit is reached again.
+pub type JSShouldTerminateCallback = ::std::option::Option<
+    unsafe extern "C" fn(ctx: JSContextRef, context: *mut ::std::os::raw::c_void) -> bool,
+>;
+
+/// A JavaScript script, parsed once and evaluated any number of times.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OpaqueJSScript([u8; 0]);
+
+/// A JavaScript script, parsed once and evaluated any number of times.
+pub type JSScriptRef = *mut OpaqueJSScript;
+
+/// A weak reference to a JavaScript object.
+#[doc(hidden)]
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct OpaqueJSWeak([u8; 0]);
+
+/// A weak reference to a JavaScript object, which doesn't keep it alive.
+pub type JSWeakRef = *const OpaqueJSWeak;
+
 unsafe extern "C" {
     /// Evaluates a string of JavaScript.
     ///
@@ -206,10 +237,154 @@ unsafe extern "C" {
     /// * [`JSValueProtect()`]
     /// * [`JSValueUnprotect()`]
     pub fn JSGarbageCollect(ctx: JSContextRef);
+
+    /// Performs a full garbage collection, synchronously.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSBasePrivate.h`), but exported by its builds. Contrary to
+    /// [`JSGarbageCollect()`], which only hints the garbage collector, the
+    /// collection is done when this function returns.
+    ///
+    /// * `ctx`: The execution context to use.
+    pub fn JSSynchronousGarbageCollectForDebugging(ctx: JSContextRef);
+
+    /// Reports an object's non-GC memory payload to the garbage collector.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSBasePrivate.h`), but exported by its builds. Use it when an object
+    /// owns memory allocated outside of the JavaScript heap, so the garbage
+    /// collector can take it into account when scheduling collections.
+    ///
+    /// * `ctx`: The execution context to use.
+    /// * `size`: The payload's size, in bytes.
+    pub fn JSReportExtraMemoryCost(ctx: JSContextRef, size: usize);
+
+    /// Gets memory usage statistics of the heap of a context group.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSBasePrivate.h`), but exported by its builds.
+    ///
+    /// * `ctx`: The execution context to use.
+    ///
+    /// Returns an object whose properties are the statistics:
+    /// `heapSize`, `heapCapacity`, `extraMemorySize`, `objectCount`,
+    /// `protectedObjectCount`, `globalObjectCount`,
+    /// `protectedGlobalObjectCount` and `objectTypeCounts`.
+    pub fn JSGetMemoryUsageStatistics(ctx: JSContextRef) -> JSObjectRef;
+
+    /// Creates a script, checking its syntax.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSScriptRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `contextGroup`: The context group the script can be evaluated in.
+    /// * `url`: The source URL of the script. It must not be `NULL`.
+    /// * `startingLineNumber`: The starting line number of the script.
+    /// * `source`: The source of the script.
+    /// * `errorMessage`: A pointer to a [`JSStringRef`] in which to store
+    ///   the syntax error message, if any. The caller owns it, and must
+    ///   release it. Pass `NULL` to discard any syntax error.
+    /// * `errorLine`: A pointer in which to store the line of the syntax
+    ///   error, if any. Pass `NULL` to discard it.
+    ///
+    /// Returns the created script, or `NULL` if it has a syntax error. The
+    /// caller must release it with [`JSScriptRelease()`].
+    pub fn JSScriptCreateFromString(
+        contextGroup: JSContextGroupRef,
+        url: JSStringRef,
+        startingLineNumber: ::std::os::raw::c_int,
+        source: JSStringRef,
+        errorMessage: *mut JSStringRef,
+        errorLine: *mut ::std::os::raw::c_int,
+    ) -> JSScriptRef;
+
+    /// Retains a script.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSScriptRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `script`: The script to retain.
+    pub fn JSScriptRetain(script: JSScriptRef);
+
+    /// Releases a script.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSScriptRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `script`: The script to release.
+    pub fn JSScriptRelease(script: JSScriptRef);
+
+    /// Evaluates a script.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSScriptRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `ctx`: The execution context to use. It must be in the context
+    ///   group the script was created for, otherwise the process aborts.
+    /// * `script`: The script to evaluate.
+    /// * `thisValue`: The value to use as `this`, or `NULL` to use the
+    ///   global object as `this`.
+    /// * `exception`: A pointer to a [`JSValueRef`] in which to store an
+    ///   exception, if any. Pass `NULL` if you do not care to store an
+    ///   exception.
+    ///
+    /// Returns the [`JSValueRef`] that results from evaluating the script,
+    /// or `NULL` if an exception is thrown.
+    pub fn JSScriptEvaluate(
+        ctx: JSContextRef,
+        script: JSScriptRef,
+        thisValue: JSValueRef,
+        exception: *mut JSValueRef,
+    ) -> JSValueRef;
+
+    /// Creates a weak reference to an object.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSWeakPrivate.h`), but exported by its builds.
+    ///
+    /// * `contextGroup`: The context group of the object.
+    /// * `object`: The object to reference.
+    ///
+    /// Returns the created weak reference. The caller must release it with
+    /// [`JSWeakRelease()`].
+    pub fn JSWeakCreate(contextGroup: JSContextGroupRef, object: JSObjectRef) -> JSWeakRef;
+
+    /// Retains a weak reference.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSWeakPrivate.h`), but exported by its builds.
+    ///
+    /// * `contextGroup`: The context group of the referenced object.
+    /// * `weak`: The weak reference to retain.
+    pub fn JSWeakRetain(contextGroup: JSContextGroupRef, weak: JSWeakRef);
+
+    /// Releases a weak reference.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSWeakPrivate.h`), but exported by its builds.
+    ///
+    /// * `contextGroup`: The context group of the referenced object.
+    /// * `weak`: The weak reference to release.
+    pub fn JSWeakRelease(contextGroup: JSContextGroupRef, weak: JSWeakRef);
+
+    /// Gets the object of a weak reference.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSWeakPrivate.h`), but exported by its builds.
+    ///
+    /// * `weak`: The weak reference.
+    ///
+    /// Returns the referenced object, or `NULL` if it has been garbage
+    /// collected.
+    pub fn JSWeakGetObject(weak: JSWeakRef) -> JSObjectRef;
 }
 
 /// A constant identifying the type of a [`JSValueRef`].
+///
+/// New types can be added to JavaScript (like `Symbol` was), hence this
+/// enum is non-exhaustive.
 #[repr(u32)]
+#[non_exhaustive]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JSType {
     /// The unique `undefined` value.
@@ -226,6 +401,11 @@ pub enum JSType {
     Object = 5,
     /// A primitive symbol value.
     Symbol = 6,
+    /// A primitive `BigInt` value.
+    ///
+    /// Only reported by recent versions of JavaScriptCore, older versions
+    /// report `BigInt`s as objects.
+    BigInt = 7,
 }
 
 /// A constant identifying the Typed Array type of a [`JSObjectRef`].
@@ -1944,6 +2124,86 @@ unsafe extern "C" {
     /// * `ctx`: The [`JSGlobalContextRef`] that you want to name.
     /// * `name`: The remote debugging name to set on `ctx`.
     pub fn JSGlobalContextSetName(ctx: JSGlobalContextRef, name: JSStringRef);
+
+    /// Gets whether the context is inspectable in Web Inspector.
+    ///
+    /// Only available in recent versions of JavaScriptCore (macOS 13.3,
+    /// iOS 16.4).
+    ///
+    /// * `ctx`: The [`JSGlobalContextRef`] that you want to check the
+    ///   inspectability of.
+    ///
+    /// Returns whether the context is inspectable in Web Inspector.
+    pub fn JSGlobalContextIsInspectable(ctx: JSGlobalContextRef) -> bool;
+
+    /// Sets whether the context is inspectable in Web Inspector. Default
+    /// value is `false`.
+    ///
+    /// Only available in recent versions of JavaScriptCore (macOS 13.3,
+    /// iOS 16.4).
+    ///
+    /// * `ctx`: The [`JSGlobalContextRef`] that you want to change the
+    ///   inspectability of.
+    /// * `inspectable`: `true` to allow Web Inspector to connect to the
+    ///   context.
+    pub fn JSGlobalContextSetInspectable(ctx: JSGlobalContextRef, inspectable: bool);
+
+    /// Sets a function to call when a promise is rejected without any
+    /// handler.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSContextRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `ctx`: The [`JSGlobalContextRef`] whose promises to track.
+    /// * `function`: The [`JSObjectRef`] of the function to call. It is
+    ///   called with the promise and its rejection reason, once the
+    ///   microtasks queue is drained, if the promise still has no handler.
+    /// * `exception`: A pointer to a [`JSValueRef`] in which to store an
+    ///   exception, if any, e.g. if `function` isn't a function. Pass
+    ///   `NULL` if you do not care to store an exception.
+    pub fn JSGlobalContextSetUnhandledRejectionCallback(
+        ctx: JSGlobalContextRef,
+        function: JSObjectRef,
+        exception: *mut JSValueRef,
+    );
+
+    /// Sets the maximum CPU time the scripts of a context group may run for,
+    /// after which `callback` decides whether to terminate them.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSContextRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `group`: The [`JSContextGroupRef`] whose scripts to limit.
+    /// * `limit`: The time limit, in seconds of CPU time.
+    /// * `callback`: The function called when the limit is reached, which
+    ///   returns `true` to terminate the script. Pass `None` to always
+    ///   terminate it.
+    /// * `context`: The pointer passed to `callback`.
+    ///
+    /// A terminated script stops with an exception that JavaScript code
+    /// can't catch.
+    ///
+    /// # See also
+    ///
+    /// * [`JSContextGroupClearExecutionTimeLimit()`]
+    pub fn JSContextGroupSetExecutionTimeLimit(
+        group: JSContextGroupRef,
+        limit: f64,
+        callback: JSShouldTerminateCallback,
+        context: *mut ::std::os::raw::c_void,
+    );
+
+    /// Clears the execution time limit of a context group.
+    ///
+    /// This function is private to JavaScriptCore (declared in
+    /// `JSContextRefPrivate.h`), but exported by its builds.
+    ///
+    /// * `group`: The [`JSContextGroupRef`] whose limit to clear.
+    ///
+    /// # See also
+    ///
+    /// * [`JSContextGroupSetExecutionTimeLimit()`]
+    pub fn JSContextGroupClearExecutionTimeLimit(group: JSContextGroupRef);
 }
 /// A UTF-16 code unit.
 ///
@@ -4,7 +4,7 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, JSContext, JSException, JSObject, JSString, JSValue};
+use crate::{class, sys, JSContext, JSException, JSObject, JSString, JSValue};
 use std::ptr;
 
 /// Evaluates a string of JavaScript.
@@ -13,10 +13,10 @@ use std::ptr;
 /// * `script`: A string containing the script to evaluate.
 /// * `this_object`: The optional object to use as `this`, or `None` to
 ///   use the global object as `this`.
-/// * `source_url`: An optional string containing a URL for the script's
-///   source file. This is used by debuggers and when reporting
-///   exceptions. Pass `None` if you do not care to include source
-///   file information.
+/// * `source_url`: A string containing a URL for the script's source
+///   file. This is used by debuggers and when reporting exceptions. Use
+///   [`JSContext::evaluate_script()`] to pass no source file information
+///   at all.
 /// * `starting_line_number`: An integer value specifying the script's
 ///   starting line number in the file located at `source_url`. This
 ///   is only used when reporting exceptions. The value is one-based,
@@ -40,13 +40,34 @@ pub fn evaluate_script<S: Into<JSString>, U: Into<JSString>>(
     source_url: U,
     starting_line_number: i32,
 ) -> Result<JSValue, JSException> {
+    evaluate_script_inner(
+        ctx,
+        script.into(),
+        this_object,
+        Some(source_url.into()),
+        starting_line_number,
+    )
+}
+
+/// Evaluates a string of JavaScript, see [`evaluate_script`].
+///
+/// Passing `None` as `source_url` passes no source file information at all.
+pub(crate) fn evaluate_script_inner(
+    ctx: &JSContext,
+    script: JSString,
+    this_object: Option<&JSObject>,
+    source_url: Option<JSString>,
+    starting_line_number: i32,
+) -> Result<JSValue, JSException> {
+    class::run_deferred_releases(ctx.raw);
+
     unsafe {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let result = sys::JSEvaluateScript(
             ctx.raw,
-            script.into().raw,
+            script.raw,
             this_object.map_or(ptr::null_mut(), |t| t.raw),
-            source_url.into().raw,
+            source_url.as_ref().map_or(ptr::null_mut(), |u| u.raw),
             starting_line_number,
             &mut exception,
         );
@@ -64,10 +85,8 @@ pub fn evaluate_script<S: Into<JSString>, U: Into<JSString>>(
 /// * `ctx`: The execution context to use.
 /// * `script`: A string containing the script to check for
 ///   syntax errors.
-/// * `source_url`: An optional string containing a URL for the script's
-///   source file. This is only used when reporting exceptions. Pass
-///   `None` if you do not care to include source file information in
-///   exceptions.
+/// * `source_url`: A string containing a URL for the script's source
+///   file. This is only used when reporting exceptions.
 /// * `starting_line_number`: An integer value specifying the script's
 ///   starting line number in the file located at `source_url`. This
 ///   is only used when reporting exceptions. The value is one-based,
@@ -135,6 +154,8 @@ pub fn check_script_syntax<S: Into<JSString>, U: Into<JSString>>(
 /// * [`JSValue::protect()`]
 /// * [`JSValue::unprotect()`]
 pub fn garbage_collect(ctx: &JSContext) {
+    class::run_deferred_releases(ctx.raw);
+
     unsafe {
         sys::JSGarbageCollect(ctx.raw);
     }
@@ -4,11 +4,228 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::{ffi::CString, ptr};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    ffi::CString,
+    mem,
+    os::raw::c_void,
+    ptr,
+    sync::OnceLock,
+};
 
 use crate::{sys, JSClass, JSContext, JSException, JSObject, JSValue};
 use thiserror::Error;
 
+/// The private data of the objects created by this crate.
+///
+/// Boxing a `dyn Any` keeps the type of the data, so that it can be checked
+/// before handing out a reference to it.
+pub(crate) type PrivateData = Box<dyn Any>;
+
+/// Gets the class from which all the classes of this crate inherit.
+///
+/// Its finalizer drops the [`PrivateData`] of the objects, which are thus
+/// the only ones whose private data is known to be a [`PrivateData`]: other
+/// objects may have been given private data of any type by other code.
+///
+/// The class has no automatic prototype, so it doesn't show up in the
+/// prototype chain of the objects. It is created once, and never released.
+pub(crate) fn private_data_class() -> sys::JSClassRef {
+    // Stored as an address, since raw pointers aren't `Sync`.
+    static CLASS: OnceLock<usize> = OnceLock::new();
+
+    *CLASS.get_or_init(|| {
+        let class_definition = sys::JSClassDefinition {
+            attributes: sys::kJSClassAttributeNoAutomaticPrototype,
+            finalize: Some(finalize_private_data),
+            ..Default::default()
+        };
+
+        unsafe { sys::JSClassCreate(&class_definition) as usize }
+    }) as sys::JSClassRef
+}
+
+/// Gets the [`PrivateData`] of `object`, or null if it has none, or if it
+/// isn't of a class of this crate.
+///
+/// # Safety
+///
+/// Ensure `ctx` and `object` are valid.
+pub(crate) unsafe fn get_private_data(
+    ctx: sys::JSContextRef,
+    object: sys::JSObjectRef,
+) -> *mut PrivateData {
+    if unsafe { sys::JSValueIsObjectOfClass(ctx, object, private_data_class()) } {
+        unsafe { sys::JSObjectGetPrivate(object) }.cast()
+    } else {
+        ptr::null_mut()
+    }
+}
+
+/// Boxes `data` into a raw pointer, to be used as the private data of an
+/// object of a class inheriting from [`private_data_class`].
+pub(crate) fn into_private_data<T: 'static>(data: T) -> *mut c_void {
+    let data: PrivateData = Box::new(data);
+
+    Box::into_raw(Box::new(data)).cast()
+}
+
+thread_local! {
+    /// Whether JavaScriptCore is dropping data owned by this crate, see
+    /// [`finalizing`].
+    static FINALIZING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, which drops data owned by JavaScriptCore from a finalizer or
+/// a deallocator, while [`is_finalizing`] returns `true`.
+pub(crate) fn finalizing<R>(f: impl FnOnce() -> R) -> R {
+    let was_finalizing = FINALIZING.with(|finalizing| finalizing.replace(true));
+    let result = f();
+    FINALIZING.with(|finalizing| finalizing.set(was_finalizing));
+
+    result
+}
+
+/// Tests whether private data is being finalized on this thread, i.e. if
+/// JavaScriptCore is collecting garbage or tearing a context down, and thus
+/// mustn't be called.
+///
+/// The `Drop` implementations of this crate which call JavaScriptCore check
+/// it, and skip the call, or [defer](defer_release) it.
+pub(crate) fn is_finalizing() -> bool {
+    FINALIZING.with(Cell::get)
+}
+
+/// A call to JavaScriptCore skipped by a `Drop` implementation because
+/// private data was being finalized, see [`defer_release`].
+pub(crate) enum DeferredRelease {
+    /// Unprotects a value of a context group.
+    Unprotect(sys::JSContextGroupRef, sys::JSValueRef),
+    /// Releases a weak reference, and the context group it retains.
+    Weak(sys::JSContextGroupRef, sys::JSWeakRef),
+}
+
+thread_local! {
+    /// The releases deferred on this thread, see [`defer_release`].
+    static DEFERRED_RELEASES: RefCell<Vec<DeferredRelease>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Defers `release` to the next call to [`run_deferred_releases`], i.e. to
+/// the next time this crate is used on this thread, instead of leaking what
+/// it releases.
+pub(crate) fn defer_release(release: DeferredRelease) {
+    DEFERRED_RELEASES.with_borrow_mut(|releases| releases.push(release));
+}
+
+/// Makes the deferred releases which can be made with `ctx`: values are
+/// unprotected when `ctx` is in their context group, weak references are
+/// always released.
+pub(crate) fn run_deferred_releases(ctx: sys::JSContextRef) {
+    if is_finalizing() {
+        return;
+    }
+
+    // Releasing a context group may finalize more private data, which
+    // defers more releases: the list mustn't be borrowed meanwhile.
+    let releases = DEFERRED_RELEASES.with_borrow_mut(mem::take);
+
+    if releases.is_empty() {
+        return;
+    }
+
+    let group = unsafe { sys::JSContextGetGroup(ctx) };
+    let remaining_releases = releases
+        .into_iter()
+        .filter(|release| match *release {
+            DeferredRelease::Unprotect(value_group, value) => {
+                if value_group != group {
+                    return true;
+                }
+
+                unsafe { sys::JSValueUnprotect(ctx, value) };
+
+                false
+            }
+            DeferredRelease::Weak(weak_group, weak) => {
+                unsafe {
+                    sys::JSWeakRelease(weak_group, weak);
+                    releasing(|| sys::JSContextGroupRelease(weak_group));
+                }
+
+                false
+            }
+        })
+        .collect::<Vec<_>>();
+
+    DEFERRED_RELEASES.with_borrow_mut(|releases| releases.extend(remaining_releases));
+}
+
+/// Unprotects the values of `group` deferred to unprotect, before the last
+/// handle to `group` is released, after which they may outlive it.
+///
+/// It needs a context of `group`, so it creates one when some values are
+/// pending. When private data is being finalized, the values are forgotten
+/// instead.
+pub(crate) fn run_deferred_unprotects(group: sys::JSContextGroupRef) {
+    let is_pending = DEFERRED_RELEASES.with_borrow(|releases| {
+        releases.iter().any(|release| {
+            matches!(*release, DeferredRelease::Unprotect(value_group, _) if value_group == group)
+        })
+    });
+
+    if !is_pending {
+        return;
+    }
+
+    if is_finalizing() {
+        DEFERRED_RELEASES.with_borrow_mut(|releases| {
+            releases.retain(|release| {
+                !matches!(*release, DeferredRelease::Unprotect(value_group, _) if value_group == group)
+            });
+        });
+
+        return;
+    }
+
+    let ctx = unsafe { sys::JSGlobalContextCreateInGroup(group, ptr::null_mut()) };
+    run_deferred_releases(ctx);
+    releasing(|| unsafe { sys::JSGlobalContextRelease(ctx) });
+}
+
+/// Calls `release`, which releases a context or a context group.
+///
+/// The release may destroy the context group, finalizing all its objects:
+/// the values their private data deferred to unprotect meanwhile are
+/// forgotten then, since they are destroyed with the group.
+pub(crate) fn releasing<F: FnOnce()>(release: F) {
+    let length = DEFERRED_RELEASES.with_borrow(Vec::len);
+
+    release();
+
+    DEFERRED_RELEASES.with_borrow_mut(|releases| {
+        let new_releases = releases.split_off(length);
+
+        releases.extend(
+            new_releases
+                .into_iter()
+                .filter(|release| matches!(release, DeferredRelease::Weak(..))),
+        );
+    });
+}
+
+/// Drops the [`PrivateData`] of `object`, if any.
+///
+/// It is the finalizer of [`private_data_class`] only: JavaScriptCore calls
+/// the finalizers of all the classes an object inherits from.
+unsafe extern "C" fn finalize_private_data(object: sys::JSObjectRef) {
+    let data = unsafe { sys::JSObjectGetPrivate(object) }.cast::<PrivateData>();
+
+    if !data.is_null() {
+        finalizing(|| drop(unsafe { Box::from_raw(data) }));
+    }
+}
+
 #[derive(Debug, Error)]
 enum JSClassError {
     #[error("classname was invalid (e.g. it contains a NULL character)")]
@@ -83,6 +300,9 @@ impl JSClass {
             ctx,
             name,
             class_definition,
+            static_functions: Vec::new(),
+            static_values: Vec::new(),
+            has_invalid_name: false,
         })
     }
 
@@ -116,6 +336,45 @@ impl JSClass {
             )
         }
     }
+
+    /// Create a new object of this class, owning some private data.
+    ///
+    /// The data can be retrieved with [`JSObject::private_data()`], e.g. from
+    /// the static functions of the class. It is dropped when the object is
+    /// garbage collected: its `Drop` implementation acts as the finalizer of
+    /// the object.
+    ///
+    /// # Finalization
+    ///
+    /// The data is dropped while JavaScriptCore is collecting garbage, or
+    /// tearing the context down, when it can't be called: the `Drop`
+    /// implementation of the data must not call into JavaScript, e.g. to
+    /// read a value or call a function, since the context may already be
+    /// gone. Dropping the [`Protected`](crate::Protected) values or the
+    /// [`JSIterator`](crate::JSIterator)s held by the data is fine: they
+    /// detect the finalization and skip their JavaScript calls, which leaves
+    /// protected values protected.
+    ///
+    /// ```rust
+    /// # use javascriptcore::*;
+    /// struct Point {
+    ///     x: f64,
+    /// }
+    ///
+    /// let ctx = JSContext::default();
+    /// let class = JSClass::builder(&ctx, "Point").unwrap().build().unwrap();
+    /// let object = class.new_object_with_private_data(Point { x: 1.5 });
+    ///
+    /// assert_eq!(object.private_data::<Point>().unwrap().x, 1.5);
+    /// ```
+    pub fn new_object_with_private_data<T: 'static>(&self, data: T) -> JSObject {
+        unsafe {
+            JSObject::from_raw(
+                self.ctx,
+                sys::JSObjectMake(self.ctx, self.raw, into_private_data(data)),
+            )
+        }
+    }
 }
 
 impl Drop for JSClass {
@@ -137,6 +396,24 @@ pub struct JSClassBuilder<'a> {
 
     /// The class definition.
     class_definition: sys::JSClassDefinition,
+
+    /// The static functions, with their names.
+    static_functions: Vec<(
+        CString,
+        sys::JSObjectCallAsFunctionCallback,
+        sys::JSPropertyAttributes,
+    )>,
+
+    /// The static values, with their names.
+    static_values: Vec<(
+        CString,
+        sys::JSObjectGetPropertyCallback,
+        sys::JSObjectSetPropertyCallback,
+        sys::JSPropertyAttributes,
+    )>,
+
+    /// Whether the name of a static function or value was invalid.
+    has_invalid_name: bool,
 }
 
 impl JSClassBuilder<'_> {
@@ -153,8 +430,112 @@ impl JSClassBuilder<'_> {
         self
     }
 
+    /// Add a static function, i.e. a method available on all the objects of
+    /// the class.
+    ///
+    /// * `name`: The name of the function.
+    /// * `function`: The function implementation. Its `this_object` is the
+    ///   object on which the function is called.
+    /// * `attributes`: A logically ORed set of `sys::kJSPropertyAttribute*`
+    ///   attributes to give to the property.
+    ///
+    /// The easiest way to generate a [`JSObjectCallAsFunctionCallback`] is by using the
+    /// [`crate::function_callback`] procedural macro.
+    ///
+    /// [`JSObjectCallAsFunctionCallback`]: sys::JSObjectCallAsFunctionCallback
+    pub fn static_function<N>(
+        mut self,
+        name: N,
+        function: sys::JSObjectCallAsFunctionCallback,
+        attributes: sys::JSPropertyAttributes,
+    ) -> Self
+    where
+        N: Into<Vec<u8>>,
+    {
+        match CString::new(name) {
+            Ok(name) => self.static_functions.push((name, function, attributes)),
+            Err(_) => self.has_invalid_name = true,
+        }
+
+        self
+    }
+
+    /// Add a static value, i.e. a property available on all the objects of
+    /// the class, computed by a getter and updated by a setter.
+    ///
+    /// * `name`: The name of the property.
+    /// * `getter`: The callback invoked when getting the property's value.
+    /// * `setter`: The callback invoked when setting the property's value.
+    ///   It may be `None` if `attributes` contains
+    ///   `sys::kJSPropertyAttributeReadOnly`.
+    /// * `attributes`: A logically ORed set of `sys::kJSPropertyAttribute*`
+    ///   attributes to give to the property.
+    pub fn static_value<N>(
+        mut self,
+        name: N,
+        getter: sys::JSObjectGetPropertyCallback,
+        setter: sys::JSObjectSetPropertyCallback,
+        attributes: sys::JSPropertyAttributes,
+    ) -> Self
+    where
+        N: Into<Vec<u8>>,
+    {
+        match CString::new(name) {
+            Ok(name) => self.static_values.push((name, getter, setter, attributes)),
+            Err(_) => self.has_invalid_name = true,
+        }
+
+        self
+    }
+
     /// Build a [`JSClass`].
-    pub fn build(self) -> Result<JSClass, JSException> {
+    ///
+    /// Objects of the class drop their private data when they are finalized, see
+    /// [`JSClass::new_object_with_private_data()`].
+    pub fn build(mut self) -> Result<JSClass, JSException> {
+        if self.has_invalid_name {
+            return Err(
+                JSValue::new_string(self.ctx, JSClassError::InvalidName.to_string()).into(),
+            );
+        }
+
+        // Both arrays must be terminated by an entry whose name is `NULL`.
+        // `JSClassCreate` copies them, so they can be dropped right after.
+        let static_functions = self
+            .static_functions
+            .iter()
+            .map(|(name, function, attributes)| sys::JSStaticFunction {
+                name: name.as_ptr(),
+                callAsFunction: *function,
+                attributes: *attributes,
+            })
+            .chain([sys::JSStaticFunction {
+                name: ptr::null(),
+                callAsFunction: None,
+                attributes: 0,
+            }])
+            .collect::<Vec<_>>();
+        let static_values = self
+            .static_values
+            .iter()
+            .map(|(name, getter, setter, attributes)| sys::JSStaticValue {
+                name: name.as_ptr(),
+                getProperty: *getter,
+                setProperty: *setter,
+                attributes: *attributes,
+            })
+            .chain([sys::JSStaticValue {
+                name: ptr::null(),
+                getProperty: None,
+                setProperty: None,
+                attributes: 0,
+            }])
+            .collect::<Vec<_>>();
+
+        self.class_definition.staticFunctions = static_functions.as_ptr();
+        self.class_definition.staticValues = static_values.as_ptr();
+        self.class_definition.parentClass = private_data_class();
+
         let class = unsafe { sys::JSClassCreate(&self.class_definition) };
 
         if class.is_null() {
@@ -181,7 +562,7 @@ impl JSClassBuilder<'_> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{constructor_callback, evaluate_script, function_callback};
+    use crate::{constructor_callback, evaluate_script, function_callback, JSContextGroup};
 
     use super::*;
 
@@ -253,4 +634,165 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn class_with_statics_and_private_data() -> Result<(), JSException> {
+        use crate as javascriptcore;
+        use std::{cell::Cell, rc::Rc};
+
+        struct Counter {
+            count: Cell<f64>,
+            dropped: Rc<Cell<bool>>,
+        }
+
+        impl Drop for Counter {
+            fn drop(&mut self) {
+                self.dropped.set(true);
+            }
+        }
+
+        #[function_callback]
+        fn increment(
+            ctx: &JSContext,
+            _function: Option<&JSObject>,
+            this_object: Option<&JSObject>,
+            _arguments: &[JSValue],
+        ) -> Result<JSValue, JSException> {
+            let Some(counter) = this_object.and_then(|this| this.private_data::<Counter>()) else {
+                return Err(JSValue::new_string(ctx, "not a counter").into());
+            };
+
+            counter.count.set(counter.count.get() + 1.);
+
+            Ok(JSValue::new_number(ctx, counter.count.get()))
+        }
+
+        unsafe extern "C" fn kind(
+            ctx: sys::JSContextRef,
+            _object: sys::JSObjectRef,
+            _property_name: sys::JSStringRef,
+            _exception: *mut sys::JSValueRef,
+        ) -> *const sys::OpaqueJSValue {
+            JSValue::new_string_inner(ctx, "counter").into()
+        }
+
+        let dropped = Rc::new(Cell::new(false));
+
+        {
+            let ctx = JSContext::default();
+            let class = JSClass::builder(&ctx, "Counter")?
+                .static_function("increment", Some(increment), sys::kJSPropertyAttributeNone)
+                .static_value("kind", Some(kind), None, sys::kJSPropertyAttributeReadOnly)
+                .build()?;
+
+            let counter = class.new_object_with_private_data(Counter {
+                count: Cell::new(41.),
+                dropped: dropped.clone(),
+            });
+            ctx.global_object()?
+                .set_property("counter", counter.into())?;
+
+            let result = evaluate_script(&ctx, "counter.increment()", None, "test.js", 1)?;
+            assert_eq!(result.as_number()?, 42.);
+
+            let result = evaluate_script(&ctx, "counter.kind", None, "test.js", 1)?;
+            assert_eq!(result.as_string()?, "counter");
+
+            // A counter method on something else than a counter.
+            let result = evaluate_script(&ctx, "counter.increment.call({})", None, "test.js", 1);
+            assert!(result.is_err());
+
+            assert!(!dropped.get());
+        }
+
+        // The private data has been finalized with the context.
+        assert!(dropped.get());
+
+        Ok(())
+    }
+
+    #[test]
+    fn private_data_holding_values() -> Result<(), JSException> {
+        use crate::{JSValue, Protected};
+        use std::{cell::Cell, rc::Rc};
+
+        struct Holder {
+            _value: Protected<JSValue>,
+            _iterator: crate::JSIterator,
+            dropped: Rc<Cell<u32>>,
+        }
+
+        impl Drop for Holder {
+            fn drop(&mut self) {
+                self.dropped.set(self.dropped.get() + 1);
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(0));
+
+        {
+            let ctx = JSContext::default();
+            let class = JSClass::builder(&ctx, "Holder")?.build()?;
+
+            for _ in 0..10 {
+                let generator = evaluate_script(
+                    &ctx,
+                    "(function* () { try { yield 1 } finally { globalThis.cleaned = true } })()",
+                    None,
+                    "test.js",
+                    1,
+                )?
+                .as_object()?;
+                let mut iterator = generator.iter()?;
+                iterator.next().transpose()?;
+
+                class.new_object_with_private_data(Holder {
+                    _value: Protected::new(JSValue::new_string(&ctx, "held")),
+                    _iterator: iterator,
+                    dropped: dropped.clone(),
+                });
+            }
+
+            assert_eq!(dropped.get(), 0);
+        }
+
+        // The private data has been finalized with the context, without
+        // calling into it.
+        assert_eq!(dropped.get(), 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn class_with_invalid_static_name() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let class = JSClass::builder(&ctx, "Foo")?
+            .static_function("a\0b", None, sys::kJSPropertyAttributeNone)
+            .build();
+
+        assert!(class.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn deferred_unprotects_run_before_group_release() {
+        let group = JSContextGroup::new();
+        let group_raw = group.raw;
+        let ctx = group.new_context();
+        let value = JSValue::new_string(&ctx, "deferred");
+        value.protect();
+
+        // As if the value was dropped by a finalizer, while running a context
+        // of another group.
+        defer_release(DeferredRelease::Unprotect(group_raw, value.raw));
+        drop(group);
+
+        let is_pending = DEFERRED_RELEASES.with_borrow(|releases| {
+            releases.iter().any(|release| {
+                matches!(*release, DeferredRelease::Unprotect(value_group, _) if value_group == group_raw)
+            })
+        });
+        assert!(!is_pending);
+    }
 }
@@ -0,0 +1,316 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, JSContext, JSException, JSObject, JSValue};
+
+/// A JavaScript `Map`.
+///
+/// The methods call the ones of `Map.prototype`, so they aren't affected by
+/// properties set on the map itself. Keys are compared like JavaScript
+/// does, i.e. objects by identity.
+///
+/// ```rust
+/// # use javascriptcore::{JSContext, JSMap, JSValue};
+/// let ctx = JSContext::default();
+/// let map = JSMap::new(&ctx).unwrap();
+///
+/// map.set(&JSValue::new_string(&ctx, "answer"), JSValue::new_number(&ctx, 42.))
+///     .unwrap();
+///
+/// assert_eq!(map.size().unwrap(), 1);
+/// assert_eq!(
+///     map.get(&JSValue::new_string(&ctx, "answer")).unwrap().as_number().unwrap(),
+///     42.
+/// );
+/// ```
+///
+/// # See also
+///
+/// * [`JSValue::as_map()`]
+pub struct JSMap {
+    object: JSObject,
+}
+
+impl JSMap {
+    /// Creates an empty `Map`, like `new Map()`.
+    pub fn new(ctx: &JSContext) -> Result<Self, JSException> {
+        Ok(Self {
+            object: construct(ctx, "Map")?,
+        })
+    }
+
+    /// Creates a [`Self`] from an object, checking that it is a `Map`.
+    pub(crate) fn from_object(object: JSObject) -> Option<Self> {
+        // The `size` getter throws on anything but a `Map`.
+        call_getter(&object, "Map", "size").ok()?;
+
+        Some(Self { object })
+    }
+
+    /// Gets the value of `key`, or `undefined` if the map doesn't have it.
+    pub fn get(&self, key: &JSValue) -> Result<JSValue, JSException> {
+        call_method(&self.object, "Map", "get", &[copy(key)])
+    }
+
+    /// Sets the value of `key`, adding it to the map if needed.
+    pub fn set(&self, key: &JSValue, value: JSValue) -> Result<(), JSException> {
+        call_method(&self.object, "Map", "set", &[copy(key), value])?;
+
+        Ok(())
+    }
+
+    /// Tests whether the map has `key`.
+    pub fn has(&self, key: &JSValue) -> Result<bool, JSException> {
+        Ok(call_method(&self.object, "Map", "has", &[copy(key)])?.as_boolean())
+    }
+
+    /// Removes `key` from the map.
+    ///
+    /// Returns `true` if the map had `key`, otherwise `false`.
+    pub fn delete(&self, key: &JSValue) -> Result<bool, JSException> {
+        Ok(call_method(&self.object, "Map", "delete", &[copy(key)])?.as_boolean())
+    }
+
+    /// Returns the number of entries of the map.
+    pub fn size(&self) -> Result<usize, JSException> {
+        Ok(call_getter(&self.object, "Map", "size")?.as_number()? as usize)
+    }
+}
+
+impl From<&JSMap> for JSObject {
+    fn from(map: &JSMap) -> Self {
+        // SAFETY: `ctx` and `raw` are valid, it's safe to use them.
+        unsafe { JSObject::from_raw(map.object.value.ctx, map.object.raw) }
+    }
+}
+
+impl From<JSMap> for JSObject {
+    fn from(map: JSMap) -> Self {
+        map.object
+    }
+}
+
+/// A JavaScript `Set`.
+///
+/// The methods call the ones of `Set.prototype`, so they aren't affected by
+/// properties set on the set itself. Values are compared like JavaScript
+/// does, i.e. objects by identity.
+///
+/// ```rust
+/// # use javascriptcore::{JSContext, JSSet, JSValue};
+/// let ctx = JSContext::default();
+/// let set = JSSet::new(&ctx).unwrap();
+///
+/// set.add(JSValue::new_number(&ctx, 1.)).unwrap();
+/// set.add(JSValue::new_number(&ctx, 1.)).unwrap();
+///
+/// assert_eq!(set.size().unwrap(), 1);
+/// assert!(set.has(&JSValue::new_number(&ctx, 1.)).unwrap());
+/// ```
+///
+/// # See also
+///
+/// * [`JSValue::as_set()`]
+pub struct JSSet {
+    object: JSObject,
+}
+
+impl JSSet {
+    /// Creates an empty `Set`, like `new Set()`.
+    pub fn new(ctx: &JSContext) -> Result<Self, JSException> {
+        Ok(Self {
+            object: construct(ctx, "Set")?,
+        })
+    }
+
+    /// Creates a [`Self`] from an object, checking that it is a `Set`.
+    pub(crate) fn from_object(object: JSObject) -> Option<Self> {
+        // The `size` getter throws on anything but a `Set`.
+        call_getter(&object, "Set", "size").ok()?;
+
+        Some(Self { object })
+    }
+
+    /// Adds `value` to the set, if it doesn't have it already.
+    pub fn add(&self, value: JSValue) -> Result<(), JSException> {
+        call_method(&self.object, "Set", "add", &[value])?;
+
+        Ok(())
+    }
+
+    /// Tests whether the set has `value`.
+    pub fn has(&self, value: &JSValue) -> Result<bool, JSException> {
+        Ok(call_method(&self.object, "Set", "has", &[copy(value)])?.as_boolean())
+    }
+
+    /// Removes `value` from the set.
+    ///
+    /// Returns `true` if the set had `value`, otherwise `false`.
+    pub fn delete(&self, value: &JSValue) -> Result<bool, JSException> {
+        Ok(call_method(&self.object, "Set", "delete", &[copy(value)])?.as_boolean())
+    }
+
+    /// Returns the number of values of the set.
+    pub fn size(&self) -> Result<usize, JSException> {
+        Ok(call_getter(&self.object, "Set", "size")?.as_number()? as usize)
+    }
+}
+
+impl From<&JSSet> for JSObject {
+    fn from(set: &JSSet) -> Self {
+        // SAFETY: `ctx` and `raw` are valid, it's safe to use them.
+        unsafe { JSObject::from_raw(set.object.value.ctx, set.object.raw) }
+    }
+}
+
+impl From<JSSet> for JSObject {
+    fn from(set: JSSet) -> Self {
+        set.object
+    }
+}
+
+/// Copies a value, to pass it as an argument.
+fn copy(value: &JSValue) -> JSValue {
+    // SAFETY: `ctx` and `raw` are valid, it's safe to use them.
+    unsafe { JSValue::from_raw(value.ctx, value.raw) }
+}
+
+/// Gets the global constructor `name`, e.g. `Map`.
+fn global_constructor(ctx: sys::JSContextRef, name: &str) -> Result<JSObject, JSException> {
+    let global_object = unsafe { JSObject::from_raw(ctx, sys::JSContextGetGlobalObject(ctx)) };
+
+    global_object.try_get_property(name)?.as_object()
+}
+
+/// Calls the global constructor `name` without arguments.
+fn construct(ctx: &JSContext, name: &str) -> Result<JSObject, JSException> {
+    global_constructor(ctx.raw, name)?
+        .call_as_constructor(&[])?
+        .as_object()
+}
+
+/// Calls the method `name` of the prototype of the global `constructor`,
+/// with `object` as `this`.
+fn call_method(
+    object: &JSObject,
+    constructor: &str,
+    name: &str,
+    arguments: &[JSValue],
+) -> Result<JSValue, JSException> {
+    let prototype = global_constructor(object.value.ctx, constructor)?
+        .try_get_property("prototype")?
+        .as_object()?;
+    let function = prototype.try_get_property(name)?.as_object()?;
+
+    function.call_as_function(Some(object), arguments)
+}
+
+/// Calls the getter of the property `name` of the prototype of the global
+/// `constructor`, with `object` as `this`.
+fn call_getter(object: &JSObject, constructor: &str, name: &str) -> Result<JSValue, JSException> {
+    let ctx = object.value.ctx;
+    let prototype = global_constructor(ctx, constructor)?.try_get_property("prototype")?;
+    let object_constructor = global_constructor(ctx, "Object")?;
+    let descriptor = object_constructor
+        .try_get_property("getOwnPropertyDescriptor")?
+        .as_object()?
+        .call_as_function(
+            Some(&object_constructor),
+            &[prototype, JSValue::new_string_inner(ctx, name)],
+        )?
+        .as_object()?;
+    let getter = descriptor.try_get_property("get")?.as_object()?;
+
+    getter.call_as_function(Some(object), &[])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{JSMap, JSSet};
+    use crate::{evaluate_script, JSContext, JSException, JSObject, JSValue};
+
+    #[test]
+    fn map() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let map = JSMap::new(&ctx)?;
+        let key = evaluate_script(&ctx, "({})", None, "foo.js", 1)?;
+
+        map.set(
+            &JSValue::new_string(&ctx, "a"),
+            JSValue::new_number(&ctx, 1.),
+        )?;
+        map.set(
+            &JSValue::new_number(&ctx, 1.),
+            JSValue::new_string(&ctx, "one"),
+        )?;
+        map.set(&key, JSValue::new_boolean(&ctx, true))?;
+
+        assert_eq!(map.size()?, 3);
+        assert_eq!(map.get(&JSValue::new_string(&ctx, "a"))?.as_number()?, 1.);
+        assert_eq!(map.get(&JSValue::new_number(&ctx, 1.))?.as_string()?, "one");
+        assert!(map.get(&key)?.as_boolean());
+
+        // Objects are compared by identity.
+        let other_key = evaluate_script(&ctx, "({})", None, "foo.js", 1)?;
+        assert!(map.has(&key)?);
+        assert!(!map.has(&other_key)?);
+        assert!(map.get(&other_key)?.is_undefined());
+
+        assert!(map.delete(&key)?);
+        assert!(!map.delete(&key)?);
+        assert_eq!(map.size()?, 2);
+
+        // The map is usable from JavaScript.
+        ctx.global_object()?
+            .set_property("map", JSObject::from(&map).into())?;
+        let result = evaluate_script(&ctx, "[...map.keys()].join()", None, "foo.js", 1)?;
+        assert_eq!(result.as_string()?, "a,1");
+
+        Ok(())
+    }
+
+    #[test]
+    fn set() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let set = evaluate_script(&ctx, "new Set(['a'])", None, "foo.js", 1)?.as_set()?;
+        let value = evaluate_script(&ctx, "({})", None, "foo.js", 1)?;
+
+        set.add(JSValue::new_string(&ctx, "a"))?;
+        set.add(evaluate_script(&ctx, "({})", None, "foo.js", 1)?)?;
+
+        assert_eq!(set.size()?, 2);
+        assert!(set.has(&JSValue::new_string(&ctx, "a"))?);
+        assert!(!set.has(&value)?);
+
+        assert!(set.delete(&JSValue::new_string(&ctx, "a"))?);
+        assert_eq!(set.size()?, 1);
+
+        assert!(JSSet::new(&ctx).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_types() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let map = evaluate_script(&ctx, "new Map()", None, "foo.js", 1)?;
+        let set = evaluate_script(&ctx, "new Set()", None, "foo.js", 1)?;
+
+        assert!(map.as_map().is_ok());
+        assert!(map.as_set().is_err());
+        assert!(set.as_set().is_ok());
+        assert!(set.as_map().is_err());
+        assert!(JSValue::new_number(&ctx, 1.).as_map().is_err());
+
+        // A `Map` subclass is still a `Map`, but an object which looks like one isn't.
+        let subclass = evaluate_script(&ctx, "new (class extends Map {})()", None, "foo.js", 1)?;
+        assert!(subclass.as_map().is_ok());
+        let fake = evaluate_script(&ctx, "({ size: 0, get() {} })", None, "foo.js", 1)?;
+        assert!(fake.as_map().is_err());
+
+        Ok(())
+    }
+}
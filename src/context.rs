@@ -6,8 +6,21 @@
 
 use sys::JSContextGetGlobalObject;
 
-use crate::{sys, JSClass, JSContext, JSContextGroup, JSException, JSObject, JSString, JSValue};
-use std::ptr;
+use crate::{
+    base, class, sys, IntoJSValue, JSClass, JSContext, JSContextGroup, JSException,
+    JSExceptionScope, JSObject, JSPromiseResolver, JSString, JSValue,
+};
+use std::any::Any;
+use std::mem::ManuallyDrop;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+use std::time::Duration;
+use std::{ptr, slice};
+use thiserror::Error;
+
+/// A Rust closure callable from JavaScript, see [`JSContext::make_function()`].
+type JSFunctionClosure =
+    Box<dyn Fn(&JSContext, &JSObject, &[JSValue]) -> Result<JSValue, JSException>>;
 
 impl JSContext {
     /// Create a new [`Self`] from its raw pointer directly.
@@ -19,6 +32,24 @@ impl JSContext {
         Self { raw }
     }
 
+    /// Returns the raw pointer of this context, e.g. to pass it to other
+    /// code using JavaScriptCore directly.
+    ///
+    /// The context isn't retained: the pointer is valid as long as this
+    /// `JSContext` is alive, unless it's retained with
+    /// [`sys::JSGlobalContextRetain()`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::{sys, JSContext};
+    /// let ctx = JSContext::default();
+    /// let global_object = unsafe { sys::JSContextGetGlobalObject(ctx.as_raw()) };
+    ///
+    /// assert!(!global_object.is_null());
+    /// ```
+    pub const fn as_raw(&self) -> sys::JSGlobalContextRef {
+        self.raw
+    }
+
     /// Creates a global JavaScript execution context and populates it
     /// with all the built-in JavaScript objects, such as `Object`,
     /// `Function`, `String`, and `Array`.
@@ -32,6 +63,29 @@ impl JSContext {
         Self::default()
     }
 
+    /// Creates a global JavaScript execution context, like
+    /// [`JSContext::new()`], and calls `f` with it.
+    ///
+    /// The context is released when `f` returns, or panics. It is handy for
+    /// tests and short scripts.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let sum = JSContext::scoped(|ctx| {
+    ///     ctx.evaluate_script("1 + 2", None, 1).unwrap().as_number().unwrap()
+    /// });
+    ///
+    /// assert_eq!(sum, 3.);
+    /// ```
+    pub fn scoped<F, R>(f: F) -> R
+    where
+        F: FnOnce(&JSContext) -> R,
+    {
+        let ctx = Self::new();
+
+        f(&ctx)
+    }
+
     /// Creates a global JavaScript execution context and populates it
     /// with all the built-in JavaScript objects, such as `Object`,
     /// `Function`, `String`, and `Array`.
@@ -48,6 +102,30 @@ impl JSContext {
         unsafe { Self::from_raw(sys::JSGlobalContextCreate(global_object_class.raw)) }
     }
 
+    /// Creates a global JavaScript execution context in the context group
+    /// `group`, and populates it with all the built-in JavaScript objects,
+    /// such as `Object`, `Function`, `String`, and `Array`.
+    ///
+    /// Contexts in the same group share the same underlying virtual machine
+    /// and heap, so they can exchange JavaScript values. A group and all its
+    /// contexts must be used from one thread at a time.
+    ///
+    /// The created global context retains `group`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSContextGroup};
+    /// let group = JSContextGroup::new();
+    /// let ctx1 = JSContext::with_group(&group);
+    /// let ctx2 = JSContext::with_group(&group);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContextGroup::new_context()`]
+    pub fn with_group(group: &JSContextGroup) -> Self {
+        group.new_context()
+    }
+
     /// Gets the context group to which a JavaScript execution context belongs.
     pub fn group(&self) -> JSContextGroup {
         let group = unsafe { sys::JSContextGetGroup(self.raw) };
@@ -63,7 +141,7 @@ impl JSContext {
     ///
     /// A `JSContext`'s name is exposed for remote debugging
     /// to make it easier to identify the context you would like to
-    /// attach to.
+    /// attach to, e.g. in the Web Inspector.
     ///
     /// Returns the name for this context, if there is one.
     ///
@@ -75,13 +153,7 @@ impl JSContext {
     /// assert!(ctx.name().is_none());
     /// ```
     pub fn name(&self) -> Option<JSString> {
-        let result = unsafe { sys::JSGlobalContextCopyName(self.raw) };
-
-        if result.is_null() {
-            None
-        } else {
-            Some(JSString { raw: result })
-        }
+        unsafe { JSString::from_raw(sys::JSGlobalContextCopyName(self.raw)) }
     }
 
     /// Sets the remote debugging name for a context.
@@ -99,13 +171,65 @@ impl JSContext {
         unsafe { sys::JSGlobalContextSetName(self.raw, name.into().raw) }
     }
 
+    /// Gets whether the context is inspectable in Web Inspector.
+    ///
+    /// This requires the `inspectable` feature, see
+    /// [`JSContext::set_inspectable()`].
+    ///
+    /// ```
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    ///
+    /// // By default, a context isn't inspectable.
+    /// assert!(!ctx.is_inspectable());
+    /// ```
+    #[cfg(feature = "inspectable")]
+    pub fn is_inspectable(&self) -> bool {
+        unsafe { sys::JSGlobalContextIsInspectable(self.raw) }
+    }
+
+    /// Sets whether the context is inspectable in Web Inspector, i.e.
+    /// whether it is listed in the remote inspector, e.g. in the Develop
+    /// menu of Safari. Give it a [name](JSContext::set_name) to identify it.
+    ///
+    /// * `inspectable`: `true` to allow Web Inspector to connect to the
+    ///   context.
+    ///
+    /// This requires the `inspectable` feature, since the underlying function
+    /// is missing from older versions of JavaScriptCore (before macOS 13.3
+    /// and iOS 16.4), and linking fails with them.
+    ///
+    /// On macOS and iOS, the remote inspector only lists the contexts of
+    /// debuggable apps: development builds, or apps signed with the
+    /// `com.apple.security.get-task-allow` entitlement. Web Inspector must
+    /// also be enabled, in the Safari settings on macOS, and in the Safari
+    /// settings of the device on iOS.
+    ///
+    /// ```
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    ///
+    /// ctx.set_name("my context");
+    /// ctx.set_inspectable(true);
+    /// assert!(ctx.is_inspectable());
+    /// ```
+    #[cfg(feature = "inspectable")]
+    pub fn set_inspectable(&self, inspectable: bool) {
+        unsafe { sys::JSGlobalContextSetInspectable(self.raw, inspectable) }
+    }
+
     /// Get the global object of this context.
     ///
+    /// This is the entry point to install host objects and functions, or to
+    /// read globals like `JSON` or `Math`.
+    ///
     /// ```rust
     /// # use javascriptcore::JSContext;
     /// let ctx = JSContext::new();
     ///
-    /// assert!(ctx.global_object().is_ok());
+    /// let global_object = ctx.global_object().unwrap();
+    /// assert!(global_object.get_property("Math").is_object());
+    /// assert!(global_object.get_property("NotAGlobal").is_undefined());
     /// ```
     pub fn global_object(&self) -> Result<JSObject, JSException> {
         let global_object = unsafe { JSContextGetGlobalObject(self.raw) };
@@ -116,55 +240,1864 @@ impl JSContext {
             Ok(unsafe { JSObject::from_raw(self.raw, global_object) })
         }
     }
-}
 
-impl Default for JSContext {
-    /// Creates a global JavaScript execution context and populates it
-    /// with all the built-in JavaScript objects, such as `Object`,
-    /// `Function`, `String`, and `Array`.
+    /// Gets a property of the global object of this context, i.e. a global
+    /// variable declared with `var`, or a property of `globalThis`.
     ///
-    /// In WebKit version 4.0 and later, the context is created in a
-    /// unique context group. Therefore, scripts may execute in it
-    /// concurrently with scripts executing in other contexts.
-    /// However, you may not use values created in the context in other
-    /// contexts.
-    fn default() -> Self {
-        unsafe { Self::from_raw(sys::JSGlobalContextCreate(ptr::null_mut())) }
+    /// Returns `undefined` if there is no such global, or the
+    /// [exception](JSException) thrown by a getter.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// ctx.evaluate_script("var answer = 42", None, 1).unwrap();
+    ///
+    /// assert_eq!(ctx.get_global("answer").unwrap().as_number().unwrap(), 42.);
+    /// assert!(ctx.get_global("NotAGlobal").unwrap().is_undefined());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::global_object()`]
+    /// * [`JSContext::set_global()`]
+    pub fn get_global<S>(&self, name: S) -> Result<JSValue, JSException>
+    where
+        S: Into<JSString>,
+    {
+        self.global_object()?.try_get_property(name)
     }
-}
 
-impl Drop for JSContext {
-    fn drop(&mut self) {
-        unsafe { sys::JSGlobalContextRelease(self.raw) }
+    /// Sets a property of the global object of this context, e.g. to install
+    /// a host value or function for scripts.
+    ///
+    /// `value` can be any Rust value that can be
+    /// [converted](IntoJSValue) into a [`JSValue`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// ctx.set_global("VERSION", "1.2.3").unwrap();
+    ///
+    /// let result = ctx.evaluate_script("`v${VERSION}`", None, 1).unwrap();
+    /// assert_eq!(result.as_string().unwrap(), "v1.2.3");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::global_object()`]
+    /// * [`JSContext::get_global()`]
+    pub fn set_global<S, V>(&self, name: S, value: V) -> Result<(), JSException>
+    where
+        S: Into<JSString>,
+        V: IntoJSValue,
+    {
+        self.global_object()?
+            .set_property(name, value.into_js_value(self))
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::JSContext;
+    /// Converts a Rust value into a [`JSValue`] in this context.
+    ///
+    /// This is a shorthand for [`IntoJSValue::into_js_value()`], handy to
+    /// build the arguments of a function call.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let max = ctx.evaluate_script("Math.max", None, 1).unwrap().as_object().unwrap();
+    ///
+    /// let result = max
+    ///     .call_as_function(None, &[ctx.value_from(1), ctx.value_from(2.5)])
+    ///     .unwrap();
+    /// assert_eq!(result.as_number().unwrap(), 2.5);
+    /// ```
+    pub fn value_from<T: IntoJSValue>(&self, value: T) -> JSValue {
+        value.into_js_value(self)
+    }
 
-    #[test]
-    fn context_group() {
-        let ctx = JSContext::new();
-        let _g = ctx.group();
-        // Nothing to do with g now...
+    /// Evaluates a string of JavaScript in this context.
+    ///
+    /// * `script`: A value that can be converted to a [`JSString`]
+    ///   containing the script to evaluate.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is used by debuggers and when reporting exceptions. Pass `None`
+    ///   if you do not care to include source file information.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    ///
+    /// Returns either the [`JSValue`] that results from evaluating the script,
+    /// or the [exception](JSException) that was thrown.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    ///
+    /// let result = ctx.evaluate_script("1 + 2", None, 1).unwrap();
+    /// assert_eq!(result.as_number().unwrap(), 3.);
+    ///
+    /// assert!(ctx.evaluate_script("throw 'oops'", Some("oops.js"), 1).is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`evaluate_script()`](crate::evaluate_script)
+    pub fn evaluate_script<S>(
+        &self,
+        script: S,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+    ) -> Result<JSValue, JSException>
+    where
+        S: Into<JSString>,
+    {
+        base::evaluate_script_inner(
+            self,
+            script.into(),
+            None,
+            source_url.map(JSString::from),
+            starting_line_number,
+        )
     }
 
-    #[test]
-    fn context_names() {
-        let ctx = JSContext::new();
-        assert!(ctx.name().is_none());
+    /// Evaluates a string of JavaScript in this context, with a given `this`
+    /// object.
+    ///
+    /// * `script`: A value that can be converted to a [`JSString`]
+    ///   containing the script to evaluate.
+    /// * `this_object`: The object to use as `this` at the top level of the
+    ///   script, instead of the global object. Variables declared by the
+    ///   script are still global.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is used by debuggers and when reporting exceptions. Pass `None`
+    ///   if you do not care to include source file information.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    ///
+    /// Returns either the [`JSValue`] that results from evaluating the script,
+    /// or the [exception](JSException) that was thrown.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::new();
+    /// let scope = JSValue::new_from_json(&ctx, r#"{"x": 40}"#).unwrap().as_object().unwrap();
+    ///
+    /// let result = ctx.evaluate_script_with_this("this.x + 2", &scope, None, 1).unwrap();
+    /// assert_eq!(result.as_number().unwrap(), 42.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::evaluate_script()`]
+    pub fn evaluate_script_with_this<S>(
+        &self,
+        script: S,
+        this_object: &JSObject,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+    ) -> Result<JSValue, JSException>
+    where
+        S: Into<JSString>,
+    {
+        base::evaluate_script_inner(
+            self,
+            script.into(),
+            Some(this_object),
+            source_url.map(JSString::from),
+            starting_line_number,
+        )
+    }
 
-        ctx.set_name("test thread");
-        assert_eq!(ctx.name().unwrap(), "test thread");
+    /// Evaluates several strings of JavaScript in this context, in sequence.
+    ///
+    /// Each script is evaluated like with [`JSContext::evaluate_script()`],
+    /// without source URL and starting at line `1`. An exception thrown by a
+    /// script doesn't prevent the next ones from being evaluated, so a result
+    /// is returned for each script, in order. Scripts share the global object,
+    /// so they see the global variables declared by the previous ones, like
+    /// the statements of a REPL.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    ///
+    /// let results = ctx.evaluate_scripts(&["var x = 1", "x += 1", "x +"]);
+    ///
+    /// assert_eq!(results[1].as_ref().unwrap().as_number().unwrap(), 2.);
+    /// assert!(results[2].is_err());
+    /// ```
+    pub fn evaluate_scripts(&self, scripts: &[&str]) -> Vec<Result<JSValue, JSException>> {
+        scripts
+            .iter()
+            .map(|script| self.evaluate_script(*script, None, 1))
+            .collect()
     }
 
-    #[test]
-    fn global_object() {
-        let ctx = JSContext::new();
-        let global_object = ctx.global_object().unwrap();
+    /// Evaluates a string of JavaScript in this context, terminating it if
+    /// it runs for longer than `timeout`, e.g. to run untrusted scripts which
+    /// may loop forever.
+    ///
+    /// * `script`: A value that can be converted to a [`JSString`]
+    ///   containing the script to evaluate.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is used by debuggers and when reporting exceptions. Pass `None`
+    ///   if you do not care to include source file information.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    /// * `timeout`: The maximum CPU time the script may run for. The limit
+    ///   is checked periodically by JavaScriptCore, so a script may run a
+    ///   bit longer before being terminated. Time spent in Rust callbacks
+    ///   counts too, but they aren't interrupted.
+    ///
+    /// Returns either the [`JSValue`] that results from evaluating the script,
+    /// [`JSEvaluationError::Exception`] if an exception was thrown, or
+    /// [`JSEvaluationError::Timeout`] if the script was terminated. A
+    /// terminated script can't catch its termination.
+    ///
+    /// The limit is set on the [context group](JSContextGroup) for the
+    /// duration of the evaluation, and cleared afterwards. It replaces any
+    /// limit of the group, e.g. one set by
+    /// [`JSContextGroup::set_execution_time_limit()`] or by another
+    /// evaluation: calls mustn't be nested, e.g. from a callback.
+    ///
+    /// A limit set by [`JSContextGroup::set_execution_time_limit()`] isn't
+    /// restored afterwards: the scripts of the group run unbounded, until
+    /// the limit is set again.
+    ///
+    /// This method is part of the [private API](crate#private-api), from
+    /// `JSContextRefPrivate.h`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSEvaluationError};
+    /// # use std::time::Duration;
+    /// let ctx = JSContext::new();
+    /// let timeout = Duration::from_millis(100);
+    ///
+    /// let result = ctx.evaluate_script_with_timeout("1 + 2", None, 1, timeout).unwrap();
+    /// assert_eq!(result.as_number().unwrap(), 3.);
+    ///
+    /// let result = ctx.evaluate_script_with_timeout("while (true) {}", None, 1, timeout);
+    /// assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::evaluate_script()`]
+    pub fn evaluate_script_with_timeout<S>(
+        &self,
+        script: S,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+        timeout: Duration,
+    ) -> Result<JSValue, JSEvaluationError>
+    where
+        S: Into<JSString>,
+    {
+        self.evaluate_script_with_time_limit(
+            script,
+            source_url,
+            starting_line_number,
+            timeout,
+            || true,
+        )
+    }
 
-        let some_property = global_object.get_property("Array");
-        assert!(!some_property.is_undefined());
+    /// Evaluates a string of JavaScript in this context, letting
+    /// `should_terminate` decide whether to terminate it each time it has run
+    /// for `time_limit`, e.g. to give more time to a script which is making
+    /// progress, or to log before terminating it.
+    ///
+    /// * `script`: A value that can be converted to a [`JSString`]
+    ///   containing the script to evaluate.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is used by debuggers and when reporting exceptions. Pass `None`
+    ///   if you do not care to include source file information.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    /// * `time_limit`: The CPU time after which `should_terminate` is
+    ///   called, see [`JSContext::evaluate_script_with_timeout()`].
+    /// * `should_terminate`: The closure called when the time limit is
+    ///   reached. It returns `true` to terminate the script, or `false` to
+    ///   let it run for another `time_limit`. A panic terminates the script.
+    ///
+    /// `should_terminate` is called on the thread running the script, while
+    /// the script is paused: it must not call into JavaScriptCore, e.g. to
+    /// evaluate a script or to read a value.
+    ///
+    /// Returns as [`JSContext::evaluate_script_with_timeout()`] does. The
+    /// same constraints apply too: calls mustn't be nested.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSEvaluationError};
+    /// # use std::time::Duration;
+    /// let ctx = JSContext::new();
+    /// let mut extensions = 0;
+    ///
+    /// let result = ctx.evaluate_script_with_time_limit(
+    ///     "while (true) {}",
+    ///     None,
+    ///     1,
+    ///     Duration::from_millis(10),
+    ///     || {
+    ///         extensions += 1;
+    ///         extensions > 3
+    ///     },
+    /// );
+    ///
+    /// assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+    /// assert_eq!(extensions, 4);
+    /// ```
+    pub fn evaluate_script_with_time_limit<S, F>(
+        &self,
+        script: S,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+        time_limit: Duration,
+        mut should_terminate: F,
+    ) -> Result<JSValue, JSEvaluationError>
+    where
+        S: Into<JSString>,
+        F: FnMut() -> bool,
+    {
+        struct TimeLimit<'f> {
+            should_terminate: &'f mut dyn FnMut() -> bool,
+            terminated: bool,
+        }
+
+        unsafe extern "C" fn call_should_terminate(
+            _ctx: sys::JSContextRef,
+            context: *mut std::os::raw::c_void,
+        ) -> bool {
+            let state = unsafe { &mut *context.cast::<TimeLimit>() };
+            // Unwinding must not cross the FFI boundary.
+            let terminate = panic::catch_unwind(AssertUnwindSafe(|| (state.should_terminate)()))
+                .unwrap_or(true);
+            state.terminated |= terminate;
+
+            terminate
+        }
+
+        let group = unsafe { sys::JSContextGetGroup(self.raw) };
+        let mut state = TimeLimit {
+            should_terminate: &mut should_terminate,
+            terminated: false,
+        };
+
+        unsafe {
+            sys::JSContextGroupSetExecutionTimeLimit(
+                group,
+                time_limit.as_secs_f64(),
+                Some(call_should_terminate),
+                ptr::from_mut(&mut state).cast(),
+            );
+        }
+
+        let result = self.evaluate_script(script, source_url, starting_line_number);
+
+        unsafe { sys::JSContextGroupClearExecutionTimeLimit(group) };
+
+        match result {
+            _ if state.terminated => Err(JSEvaluationError::Timeout),
+            Ok(value) => Ok(value),
+            Err(exception) => Err(JSEvaluationError::Exception(exception)),
+        }
+    }
+
+    /// Checks for syntax errors in a string of JavaScript, without
+    /// evaluating it.
+    ///
+    /// * `script`: A value that can be converted to a [`JSString`]
+    ///   containing the script to check for syntax errors.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is only used when reporting exceptions. Pass `None` if you do not
+    ///   care to include source file information in exceptions.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    ///
+    /// Returns `Ok` if the script is syntactically correct, otherwise the
+    /// `SyntaxError` [exception](JSException), whose `message` and `line`
+    /// properties describe the error.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    ///
+    /// assert!(ctx.check_syntax("1 + 2", None, 1).is_ok());
+    ///
+    /// let exception = ctx.check_syntax("1 +", Some("oops.js"), 1).unwrap_err();
+    /// assert_eq!(exception.name().unwrap(), "SyntaxError");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`check_script_syntax()`](crate::check_script_syntax)
+    /// * [`JSContext::evaluate_script()`]
+    pub fn check_syntax<S>(
+        &self,
+        script: S,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+    ) -> Result<(), JSException>
+    where
+        S: Into<JSString>,
+    {
+        let script: JSString = script.into();
+        let source_url = source_url.map(JSString::from);
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let is_valid = unsafe {
+            sys::JSCheckScriptSyntax(
+                self.raw,
+                script.raw,
+                source_url.as_ref().map_or(ptr::null_mut(), |url| url.raw),
+                starting_line_number,
+                &mut exception,
+            )
+        };
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(unsafe { JSValue::from_raw(self.raw, exception) }.into())
+        }
+    }
+
+    /// Creates a JavaScript array in this context.
+    ///
+    /// * `items`: The array items as [`JSValue`]s. An empty slice creates
+    ///   `[]`.
+    ///
+    /// Returns the array as a [`JSObject`], otherwise an
+    /// [exception](JSException).
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::new();
+    /// let array = ctx
+    ///     .new_array(&[JSValue::new_number(&ctx, 1.), JSValue::new_boolean(&ctx, true)])
+    ///     .unwrap();
+    ///
+    /// assert!(array.is_array());
+    /// assert_eq!(array.get_property("length").as_number().unwrap(), 2.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSValue::new_array()`]
+    /// * [`JSValue::is_array()`]
+    pub fn new_array(&self, items: &[JSValue]) -> Result<JSObject, JSException> {
+        JSValue::new_array(self, items)?.as_object()
+    }
+
+    /// Creates a JavaScript `Date` in this context.
+    ///
+    /// * `milliseconds`: The number of milliseconds since the Unix epoch,
+    ///   `1970-01-01T00:00:00Z`.
+    ///
+    /// JavaScript dates hold an `f64` number of milliseconds, so they have a
+    /// millisecond precision: the fractional part of `milliseconds` is
+    /// truncated. They range over ±8.64 × 10<sup>15</sup> milliseconds,
+    /// i.e. ±100,000,000 days; outside of this range, or for `NaN`, the date
+    /// is an `Invalid Date`.
+    ///
+    /// Returns the date as a [`JSObject`], otherwise an
+    /// [exception](JSException).
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let date = ctx.new_date(0.).unwrap();
+    ///
+    /// assert!(date.is_date());
+    /// assert_eq!(date.date_milliseconds(), Some(0.));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::date_milliseconds()`]
+    /// * [`JSValue::is_date()`]
+    pub fn new_date(&self, milliseconds: f64) -> Result<JSObject, JSException> {
+        let arguments = [JSValue::new_number(self, milliseconds).raw];
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let result = unsafe {
+            sys::JSObjectMakeDate(
+                self.raw,
+                arguments.len(),
+                arguments.as_ptr(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(self.raw, exception) }.into());
+        }
+
+        if result.is_null() {
+            return Err(JSValue::new_string(self, "Failed to make a new date").into());
+        }
+
+        Ok(unsafe { JSObject::from_raw(self.raw, result) })
+    }
+
+    /// Creates a JavaScript `Error` in this context, like `new Error(message)`,
+    /// e.g. to reject a promise or to throw from a Rust function.
+    ///
+    /// * `message`: A value that can be converted to a [`JSString`]
+    ///   containing the `message` of the error.
+    ///
+    /// The error has a `stack` property, like the errors created in
+    /// JavaScript.
+    ///
+    /// Returns the error as a [`JSObject`], otherwise an
+    /// [exception](JSException).
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSException, JSValue};
+    /// let ctx = JSContext::new();
+    /// let error = ctx.new_error("oops").unwrap();
+    ///
+    /// assert_eq!(error.get_property("message").as_string().unwrap(), "oops");
+    /// assert_eq!(JSException::from(JSValue::from(error)).to_string(), "Error: oops");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::new_range_error()`]
+    /// * [`JSContext::new_type_error()`]
+    pub fn new_error<S: Into<JSString>>(&self, message: S) -> Result<JSObject, JSException> {
+        let arguments = [JSValue::new_string(self, message).raw];
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let result = unsafe {
+            sys::JSObjectMakeError(
+                self.raw,
+                arguments.len(),
+                arguments.as_ptr(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(self.raw, exception) }.into());
+        }
+
+        if result.is_null() {
+            return Err(JSValue::new_string(self, "Failed to make a new error").into());
+        }
+
+        Ok(unsafe { JSObject::from_raw(self.raw, result) })
+    }
+
+    /// Creates a JavaScript `TypeError` in this context, like
+    /// `new TypeError(message)`.
+    ///
+    /// See [`JSContext::new_error()`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let error = ctx.new_type_error("not a number").unwrap();
+    ///
+    /// assert_eq!(error.get_property("name").as_string().unwrap(), "TypeError");
+    /// ```
+    pub fn new_type_error<S: Into<JSString>>(&self, message: S) -> Result<JSObject, JSException> {
+        self.new_error_of_type("TypeError", message.into())
+    }
+
+    /// Creates a JavaScript `RangeError` in this context, like
+    /// `new RangeError(message)`.
+    ///
+    /// See [`JSContext::new_error()`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let error = ctx.new_range_error("too large").unwrap();
+    ///
+    /// assert_eq!(error.get_property("name").as_string().unwrap(), "RangeError");
+    /// ```
+    pub fn new_range_error<S: Into<JSString>>(&self, message: S) -> Result<JSObject, JSException> {
+        self.new_error_of_type("RangeError", message.into())
+    }
+
+    /// Creates an error by calling the global constructor named
+    /// `constructor`.
+    pub(crate) fn new_error_of_type(
+        &self,
+        constructor: &str,
+        message: JSString,
+    ) -> Result<JSObject, JSException> {
+        self.global_object()?
+            .try_get_property(constructor)?
+            .as_object()?
+            .call_as_constructor(&[JSValue::new_string(self, message)])?
+            .as_object()
+    }
+
+    /// Creates a JavaScript `RegExp` in this context, like `new RegExp(pattern,
+    /// flags)`.
+    ///
+    /// * `pattern`: The regular expression pattern, with the JavaScript
+    ///   syntax and semantics.
+    /// * `flags`: Any combination of the following flags, or `""`:
+    ///   - `g`: global search, for `exec` to iterate over all the matches,
+    ///   - `i`: case-insensitive search,
+    ///   - `m`: multi-line search, where `^` and `$` match at line breaks,
+    ///   - `s`: allows `.` to match line breaks,
+    ///   - `u`: Unicode mode, where the pattern and the input are treated as
+    ///     sequences of code points instead of UTF-16 code units,
+    ///   - `y`: sticky search, starting at the `lastIndex` of the `RegExp`.
+    ///
+    /// Returns the `RegExp` as a [`JSObject`], otherwise an
+    /// [exception](JSException), e.g. a `SyntaxError` for an invalid pattern
+    /// or an unknown flag.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let regexp = ctx.new_regexp("^h(el+)o$", "i").unwrap();
+    ///
+    /// assert!(regexp.regexp_test("HELLO").unwrap());
+    /// assert!(!regexp.regexp_test("help").unwrap());
+    ///
+    /// assert!(ctx.new_regexp("(", "").is_err());
+    /// assert!(ctx.new_regexp("a", "x").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::regexp_exec()`]
+    /// * [`JSObject::regexp_test()`]
+    pub fn new_regexp<P, F>(&self, pattern: P, flags: F) -> Result<JSObject, JSException>
+    where
+        P: Into<JSString>,
+        F: Into<JSString>,
+    {
+        let arguments = [
+            JSValue::new_string(self, pattern).raw,
+            JSValue::new_string(self, flags).raw,
+        ];
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let result = unsafe {
+            sys::JSObjectMakeRegExp(
+                self.raw,
+                arguments.len(),
+                arguments.as_ptr(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(self.raw, exception) }.into());
+        }
+
+        if result.is_null() {
+            return Err(
+                JSValue::new_string(self, "Failed to make a new regular expression").into(),
+            );
+        }
+
+        Ok(unsafe { JSObject::from_raw(self.raw, result) })
+    }
+
+    /// Creates a JavaScript promise in this context, to be resolved or
+    /// rejected from Rust.
+    ///
+    /// Returns the promise as a [`JSObject`], and the
+    /// [`JSPromiseResolver`] settling it, otherwise an
+    /// [exception](JSException).
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::new();
+    /// let (promise, resolver) = ctx.new_promise().unwrap();
+    ///
+    /// let global_object = ctx.global_object().unwrap();
+    /// global_object.set_property("promise", promise.into()).unwrap();
+    /// ctx.evaluate_script("promise.then((v) => { globalThis.result = v })", None, 1)
+    ///     .unwrap();
+    ///
+    /// // … Later, once the work is done.
+    /// resolver.resolve(JSValue::new_number(&ctx, 42.)).unwrap();
+    ///
+    /// assert_eq!(global_object.get_property("result").as_number().unwrap(), 42.);
+    /// ```
+    pub fn new_promise(&self) -> Result<(JSObject, JSPromiseResolver), JSException> {
+        let mut resolve: sys::JSObjectRef = ptr::null_mut();
+        let mut reject: sys::JSObjectRef = ptr::null_mut();
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let promise = unsafe {
+            sys::JSObjectMakeDeferredPromise(self.raw, &mut resolve, &mut reject, &mut exception)
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(self.raw, exception) }.into());
+        }
+
+        if promise.is_null() || resolve.is_null() || reject.is_null() {
+            return Err(JSValue::new_string(self, "Failed to make a new promise").into());
+        }
+
+        let (promise, resolve, reject) = unsafe {
+            (
+                JSObject::from_raw(self.raw, promise),
+                JSObject::from_raw(self.raw, resolve),
+                JSObject::from_raw(self.raw, reject),
+            )
+        };
+
+        Ok((promise, JSPromiseResolver::new(resolve, reject)))
+    }
+
+    /// Sets a handler called with the reason of each promise rejected
+    /// without any rejection handler, e.g. to log errors which would
+    /// otherwise go unnoticed.
+    ///
+    /// A rejection is unhandled if the promise still has no handler once
+    /// the microtasks are drained, i.e. when the outermost call into
+    /// JavaScriptCore returns, see [`JSContext`].
+    /// Setting a handler replaces the previous one.
+    ///
+    /// Exceptions thrown synchronously never go unnoticed: they are returned
+    /// by the call which threw them, like [`JSContext::evaluate_script()`].
+    ///
+    /// Returns an [exception](JSException) if the handler couldn't be set.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// # use std::{cell::RefCell, rc::Rc};
+    /// let ctx = JSContext::new();
+    /// let reasons = Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let log = reasons.clone();
+    /// ctx.set_unhandled_rejection_handler(move |_ctx, reason| {
+    ///     log.borrow_mut().push(reason.as_string().unwrap().to_string());
+    /// })
+    /// .unwrap();
+    ///
+    /// ctx.evaluate_script("Promise.reject('oops')", None, 1).unwrap();
+    ///
+    /// assert_eq!(*reasons.borrow(), ["oops"]);
+    /// ```
+    pub fn set_unhandled_rejection_handler<F>(&self, handler: F) -> Result<(), JSException>
+    where
+        F: Fn(&JSContext, &JSValue) + 'static,
+    {
+        let function = self.make_function("unhandledRejection", move |ctx, _this, arguments| {
+            // It's called with the promise, and the reason.
+            let undefined = JSValue::new_undefined(ctx);
+            handler(ctx, arguments.get(1).unwrap_or(&undefined));
+
+            Ok(undefined)
+        });
+
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        unsafe {
+            sys::JSGlobalContextSetUnhandledRejectionCallback(
+                self.raw,
+                function.raw,
+                &mut exception,
+            );
+        }
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(self.raw, exception) }.into());
+        }
+
+        Ok(())
+    }
+
+    /// Runs `function` in a scope recording the exceptions thrown by raw
+    /// JavaScriptCore calls, so that the `exception` out-pointers they take
+    /// are never checked by hand.
+    ///
+    /// Returns either the result of `function`, or the first
+    /// [exception](JSException) recorded by the scope, even if `function`
+    /// ignored it.
+    ///
+    /// ```
+    /// # use javascriptcore::{sys, JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let value = JSValue::new_string(&ctx, "42");
+    ///
+    /// let number = ctx.with_exception_scope(|scope| {
+    ///     let raw_value: sys::JSValueRef = value.into();
+    ///
+    ///     scope.call(|ctx, exception| unsafe { sys::JSValueToNumber(ctx, raw_value, exception) })
+    /// });
+    ///
+    /// assert_eq!(number.unwrap(), 42.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSExceptionScope::call()`]
+    pub fn with_exception_scope<'c, F, T>(&'c self, function: F) -> Result<T, JSException>
+    where
+        F: FnOnce(&mut JSExceptionScope<'c>) -> Result<T, JSException>,
+    {
+        let mut scope = JSExceptionScope::new(self);
+        let result = function(&mut scope);
+
+        scope.finish(result)
+    }
+
+    /// Gets memory usage statistics of the JavaScript heap.
+    ///
+    /// The heap is shared by all the contexts of a [context
+    /// group](JSContextGroup), so are the statistics. They are best-effort:
+    /// they come from the [private API](crate#private-api)
+    /// (`JSBasePrivate.h`), and a statistic it doesn't report is `0`. Sizes
+    /// are in bytes. Objects which survived the last garbage collection are
+    /// counted, as well as all the objects allocated since, dead or not, so
+    /// [collect the garbage](JSContext::collect_garbage) first for precise
+    /// values.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let statistics = ctx.heap_statistics();
+    ///
+    /// assert!(statistics.heap_size <= statistics.heap_capacity);
+    /// ```
+    pub fn heap_statistics(&self) -> JSHeapStatistics {
+        let statistics = unsafe { sys::JSGetMemoryUsageStatistics(self.raw) };
+
+        if statistics.is_null() {
+            return JSHeapStatistics::default();
+        }
+
+        let statistics = unsafe { JSObject::from_raw(self.raw, statistics) };
+        let statistic = |name| {
+            statistics
+                .try_get_property(name)
+                .ok()
+                .and_then(|value| value.as_f64())
+                .map_or(0, |value| value as usize)
+        };
+
+        JSHeapStatistics {
+            heap_size: statistic("heapSize"),
+            heap_capacity: statistic("heapCapacity"),
+            extra_memory_size: statistic("extraMemorySize"),
+            object_count: statistic("objectCount"),
+            protected_object_count: statistic("protectedObjectCount"),
+            global_object_count: statistic("globalObjectCount"),
+            protected_global_object_count: statistic("protectedGlobalObjectCount"),
+        }
+    }
+
+    /// Performs a JavaScript garbage collection.
+    ///
+    /// This is only a hint: JavaScript values that are on the machine stack,
+    /// in a register, protected, or referenced from a root object are not
+    /// collected, and when the memory is reclaimed isn't guaranteed. It is
+    /// useful in tests, or to reclaim memory after a large batch of work.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// // ... Do things ...
+    /// ctx.collect_garbage();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`garbage_collect()`](crate::garbage_collect)
+    /// * [`Protected`](crate::Protected)
+    pub fn collect_garbage(&self) {
+        base::garbage_collect(self);
+    }
+
+    /// Reports memory allocated outside of the JavaScript heap, but kept
+    /// alive by JavaScript objects, e.g. the bytes of a large
+    /// [`ArrayBuffer`](JSValue::new_array_buffer).
+    ///
+    /// The garbage collector only sees the size of the objects themselves,
+    /// so it may otherwise collect them too late. Reporting their extra cost
+    /// makes it collect sooner. It is only a hint, which isn't tied to any
+    /// object: there is nothing to report when the memory is freed.
+    ///
+    /// The C API of JavaScriptCore has no way to notify it of memory
+    /// pressure, but [`JSContext::collect_garbage()`] can be called instead.
+    ///
+    /// This method is part of the [private API](crate#private-api), from
+    /// `JSBasePrivate.h`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::new();
+    /// let bytes = vec![0u8; 16 * 1024 * 1024];
+    /// let size = bytes.len();
+    /// let buffer = JSValue::new_array_buffer(&ctx, bytes).unwrap();
+    ///
+    /// ctx.report_extra_memory_cost(size);
+    /// ```
+    pub fn report_extra_memory_cost(&self, bytes: usize) {
+        unsafe { sys::JSReportExtraMemoryCost(self.raw, bytes) }
+    }
+
+    /// Creates a JavaScript function implemented by a Rust closure.
+    ///
+    /// * `name`: A value that can be converted to a [`JSString`] containing
+    ///   the function's name, i.e. its `name` property.
+    /// * `callback`: The closure to call when the function is called. It
+    ///   receives the context, the `this` object, and the arguments.
+    ///
+    /// Contrary to [`JSValue::new_function()`], the callback can capture its
+    /// environment. It is owned by the function, and dropped when the function
+    /// is garbage collected.
+    ///
+    /// Returning an `Err` throws the exception in JavaScript. If the callback
+    /// panics, the panic is caught and thrown as an exception too, since
+    /// unwinding must not cross the FFI boundary.
+    ///
+    /// The function isn't installed anywhere; use [`JSObject::set_property()`]
+    /// on the [global object](JSContext::global_object) to expose it to
+    /// scripts.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::new();
+    /// let offset = 10.;
+    ///
+    /// let add = ctx.make_function("add", move |ctx, _this, arguments| {
+    ///     let sum = arguments
+    ///         .iter()
+    ///         .map(JSValue::as_number)
+    ///         .sum::<Result<f64, _>>()?;
+    ///
+    ///     Ok(JSValue::new_number(ctx, sum + offset))
+    /// });
+    ///
+    /// ctx.global_object().unwrap().set_property("add", add.into()).unwrap();
+    ///
+    /// let result = ctx.evaluate_script("add(1, 2)", None, 1).unwrap();
+    /// assert_eq!(result.as_number().unwrap(), 13.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSValue::new_function()`]
+    pub fn make_function<N, F>(&self, name: N, callback: F) -> JSObject
+    where
+        N: Into<JSString>,
+        F: Fn(&JSContext, &JSObject, &[JSValue]) -> Result<JSValue, JSException> + 'static,
+    {
+        // Stored as an address, since raw pointers aren't `Sync`. The class
+        // is created once, and never released.
+        static CLASS: OnceLock<usize> = OnceLock::new();
+
+        let class = *CLASS.get_or_init(|| {
+            let class_definition = sys::JSClassDefinition {
+                attributes: sys::kJSClassAttributeNoAutomaticPrototype,
+                className: c"Function".as_ptr(),
+                parentClass: class::private_data_class(),
+                callAsFunction: Some(call_closure),
+                ..Default::default()
+            };
+
+            unsafe { sys::JSClassCreate(&class_definition) as usize }
+        }) as sys::JSClassRef;
+
+        let closure: JSFunctionClosure = Box::new(callback);
+
+        let function = unsafe {
+            let function = sys::JSObjectMake(self.raw, class, class::into_private_data(closure));
+
+            // The class has no prototype, so that the function inherits
+            // from `Function.prototype`, like native functions, whose
+            // prototype can't be changed by scripts.
+            let native_function =
+                sys::JSObjectMakeFunctionWithCallback(self.raw, ptr::null_mut(), None);
+            sys::JSObjectSetPrototype(
+                self.raw,
+                function,
+                sys::JSObjectGetPrototype(self.raw, native_function),
+            );
+
+            JSObject::from_raw(self.raw, function)
+        };
+
+        let name: JSString = name.into();
+        let name = JSValue::new_string(self, name);
+
+        unsafe {
+            sys::JSObjectSetProperty(
+                self.raw,
+                function.raw,
+                JSString::from("name").raw,
+                name.raw,
+                sys::kJSPropertyAttributeReadOnly
+                    | sys::kJSPropertyAttributeDontEnum
+                    | sys::kJSPropertyAttributeDontDelete,
+                ptr::null_mut(),
+            );
+        }
+
+        function
+    }
+}
+
+/// An error returned by [`JSContext::evaluate_script_with_timeout()`].
+#[derive(Debug, Error)]
+pub enum JSEvaluationError {
+    /// The script threw an exception.
+    #[error("{0}")]
+    Exception(#[from] JSException),
+
+    /// The script ran for too long, and was terminated.
+    #[error("script execution timed out")]
+    Timeout,
+}
+
+/// Memory usage statistics of a JavaScript heap.
+///
+/// It is created by [`JSContext::heap_statistics()`], see there for how
+/// precise the statistics are.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JSHeapStatistics {
+    /// The size of the objects, in bytes.
+    pub heap_size: usize,
+    /// The size of the memory reserved for objects, in bytes.
+    pub heap_capacity: usize,
+    /// The size of the memory held outside of the heap by objects, like
+    /// the bytes of `ArrayBuffer`s, in bytes.
+    pub extra_memory_size: usize,
+    /// The number of objects.
+    pub object_count: usize,
+    /// The number of [protected](crate::JSValue::protect) objects.
+    pub protected_object_count: usize,
+    /// The number of global objects, i.e. of contexts.
+    pub global_object_count: usize,
+    /// The number of protected global objects.
+    pub protected_global_object_count: usize,
+}
+
+/// Calls the [`JSFunctionClosure`] held in the private data of `function`.
+unsafe extern "C" fn call_closure(
+    raw_ctx: sys::JSContextRef,
+    function: sys::JSObjectRef,
+    this_object: sys::JSObjectRef,
+    argument_count: usize,
+    arguments: *const sys::JSValueRef,
+    exception: *mut sys::JSValueRef,
+) -> *const sys::OpaqueJSValue {
+    // Let's not drop `ctx`, otherwise it will close the context.
+    let ctx = ManuallyDrop::new(unsafe { JSContext::from_raw(raw_ctx as *mut _) });
+    let function = unsafe { JSObject::from_raw(raw_ctx, function) };
+
+    let Some(closure) = function.private_data::<JSFunctionClosure>() else {
+        unsafe {
+            *exception =
+                JSValue::new_string_inner(raw_ctx, "The Rust function is not available").into();
+        }
+
+        return ptr::null();
+    };
+
+    let this_object = if this_object.is_null() {
+        unsafe { JSContextGetGlobalObject(raw_ctx) }
+    } else {
+        this_object
+    };
+    let this_object = unsafe { JSObject::from_raw(raw_ctx, this_object) };
+
+    let arguments = if argument_count == 0 || arguments.is_null() {
+        Vec::new()
+    } else {
+        unsafe { slice::from_raw_parts(arguments, argument_count) }
+            .iter()
+            .map(|value| unsafe { JSValue::from_raw(raw_ctx, *value) })
+            .collect::<Vec<_>>()
+    };
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        closure(&ctx, &this_object, arguments.as_slice())
+    }));
+
+    let result = match result {
+        Ok(result) => result,
+        Err(payload) => Err(JSValue::new_string_inner(
+            raw_ctx,
+            format!("Rust function panicked: {}", panic_message(&*payload)),
+        )
+        .into()),
+    };
+
+    match result {
+        Ok(value) => {
+            unsafe { *exception = ptr::null_mut() };
+
+            value.raw
+        }
+        Err(exc) => {
+            unsafe { *exception = exc.into() };
+
+            ptr::null()
+        }
+    }
+}
+
+/// Extracts the message of a panic payload, as printed by the default panic
+/// hook.
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "Box<dyn Any>"
+    }
+}
+
+impl Default for JSContext {
+    /// Creates a global JavaScript execution context and populates it
+    /// with all the built-in JavaScript objects, such as `Object`,
+    /// `Function`, `String`, and `Array`.
+    ///
+    /// In WebKit version 4.0 and later, the context is created in a
+    /// unique context group. Therefore, scripts may execute in it
+    /// concurrently with scripts executing in other contexts.
+    /// However, you may not use values created in the context in other
+    /// contexts.
+    fn default() -> Self {
+        unsafe { Self::from_raw(sys::JSGlobalContextCreate(ptr::null_mut())) }
+    }
+}
+
+impl Drop for JSContext {
+    fn drop(&mut self) {
+        class::run_deferred_releases(self.raw);
+        class::releasing(|| unsafe { sys::JSGlobalContextRelease(self.raw) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JSContext, JSContextGroup, JSEvaluationError, JSException, JSType, JSValue};
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    #[test]
+    fn context_group() {
+        let ctx = JSContext::new();
+        let _g = ctx.group();
+        // Nothing to do with g now...
+    }
+
+    #[test]
+    fn with_group() {
+        let group = JSContextGroup::new();
+        let ctx1 = JSContext::with_group(&group);
+        let ctx2 = JSContext::with_group(&group);
+
+        assert_eq!(ctx1.group().raw, group.raw);
+        assert_eq!(ctx2.group().raw, group.raw);
+
+        // Each context has its own global object.
+        let global_object1 = ctx1.global_object().unwrap();
+        let global_object2 = ctx2.global_object().unwrap();
+        assert_ne!(global_object1.raw, global_object2.raw);
+
+        global_object1
+            .set_property("foo", JSValue::new_number(&ctx1, 42.))
+            .unwrap();
+        assert!(global_object1.has_property("foo"));
+        assert!(!global_object2.has_property("foo"));
+    }
+
+    #[cfg(feature = "inspectable")]
+    #[test]
+    fn inspectable() {
+        let ctx = JSContext::new();
+        assert!(!ctx.is_inspectable());
+
+        ctx.set_inspectable(true);
+        assert!(ctx.is_inspectable());
+
+        ctx.set_inspectable(false);
+        assert!(!ctx.is_inspectable());
+    }
+
+    #[test]
+    fn unhandled_rejections() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let reasons = Rc::new(RefCell::new(Vec::new()));
+
+        let log = reasons.clone();
+        ctx.set_unhandled_rejection_handler(move |_ctx, reason| {
+            log.borrow_mut()
+                .push(reason.as_string().unwrap().to_string());
+        })?;
+
+        ctx.evaluate_script("Promise.reject('handled').catch(() => {})", None, 1)?;
+        assert!(reasons.borrow().is_empty());
+
+        ctx.evaluate_script(
+            "new Promise((_, reject) => reject(new Error('oops')))",
+            None,
+            1,
+        )?;
+        assert_eq!(*reasons.borrow(), ["Error: oops"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_script_with_timeout() {
+        let ctx = JSContext::new();
+        let timeout = Duration::from_millis(50);
+
+        let result = ctx.evaluate_script_with_timeout("6 * 7", None, 1, timeout);
+        assert_eq!(result.unwrap().as_number().unwrap(), 42.);
+
+        let result = ctx.evaluate_script_with_timeout("while (true) {}", None, 1, timeout);
+        assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+
+        // Termination can't be caught.
+        let script = "try { while (true) {} } catch { 'caught' }";
+        let result = ctx.evaluate_script_with_timeout(script, None, 1, timeout);
+        assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+
+        let result = ctx.evaluate_script_with_timeout("throw 'oops'", None, 1, timeout);
+        let Err(JSEvaluationError::Exception(exception)) = result else {
+            panic!("expected an exception");
+        };
+        assert_eq!(exception.to_string(), "oops");
+
+        // The limit is cleared afterwards, and the context is still usable.
+        let result = ctx.evaluate_script("'still alive'", None, 1).unwrap();
+        assert_eq!(result.as_string().unwrap(), "still alive");
+    }
+
+    #[test]
+    fn evaluate_script_with_timeout_clears_group_limit() {
+        let group = JSContextGroup::new();
+        let ctx = group.new_context();
+        let finite_loop = "let i = 0; while (i < 1e8) { i++ } i";
+
+        group.set_execution_time_limit(Duration::from_millis(10));
+        assert!(ctx.evaluate_script("while (true) {}", None, 1).is_err());
+
+        let result = ctx.evaluate_script_with_timeout("1", None, 1, Duration::from_secs(60));
+        assert_eq!(result.unwrap().as_number().unwrap(), 1.);
+
+        // The limit of the group is gone, not restored.
+        let result = ctx.evaluate_script(finite_loop, None, 1).unwrap();
+        assert_eq!(result.as_number().unwrap(), 1e8);
+
+        group.clear_execution_time_limit();
+    }
+
+    #[test]
+    fn evaluate_script_with_time_limit() {
+        let ctx = JSContext::new();
+        let time_limit = Duration::from_millis(20);
+        let mut calls = 0;
+
+        // Deny termination once, then allow it.
+        let result =
+            ctx.evaluate_script_with_time_limit("while (true) {}", None, 1, time_limit, || {
+                calls += 1;
+                calls > 1
+            });
+        assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+        assert_eq!(calls, 2);
+
+        // Quick scripts never call the closure.
+        let result =
+            ctx.evaluate_script_with_time_limit("1", None, 1, time_limit, || unreachable!());
+        assert_eq!(result.unwrap().as_number().unwrap(), 1.);
+
+        // A panic terminates the script.
+        let result =
+            ctx.evaluate_script_with_time_limit("while (true) {}", None, 1, time_limit, || {
+                panic!("oops")
+            });
+        assert!(matches!(result, Err(JSEvaluationError::Timeout)));
+    }
+
+    #[test]
+    fn new_errors() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let error = ctx.new_error("plain")?;
+        assert_eq!(error.get_property("name").as_string()?, "Error");
+        assert_eq!(error.get_property("message").as_string()?, "plain");
+        assert!(error.get_property("stack").is_string());
+
+        let error = ctx.new_range_error("out of range")?;
+        assert_eq!(error.get_property("name").as_string()?, "RangeError");
+
+        let check = ctx.make_function("check", |ctx, _this, arguments| {
+            if arguments.is_empty() {
+                let error = ctx.new_type_error("expected an argument")?;
+                return Err(JSValue::from(error).into());
+            }
+
+            Ok(JSValue::new_undefined(ctx))
+        });
+        ctx.global_object()?.set_property("check", check.into())?;
+
+        let result = ctx.evaluate_script(
+            "try { check() } catch (e) { [e instanceof TypeError, e.name, e.message].join() }",
+            None,
+            1,
+        )?;
+        assert_eq!(result.as_string()?, "true,TypeError,expected an argument");
+
+        Ok(())
+    }
+
+    #[test]
+    fn context_names() {
+        let ctx = JSContext::new();
+        assert!(ctx.name().is_none());
+
+        ctx.set_name("test thread");
+        assert_eq!(ctx.name().unwrap(), "test thread");
+
+        // Names tell contexts apart.
+        let other = JSContext::new();
+        other.set_name("other thread");
+        assert_eq!(ctx.name().unwrap(), "test thread");
+        assert_eq!(other.name().unwrap(), "other thread");
+
+        ctx.set_name("renamed 😄");
+        assert_eq!(ctx.name().unwrap(), "renamed 😄");
+    }
+
+    #[test]
+    fn evaluate_script() {
+        let ctx = JSContext::new();
+
+        let result = ctx.evaluate_script("1 + 2", None, 1).unwrap();
+        assert_eq!(result.as_number().unwrap(), 3.);
+
+        let result = ctx
+            .evaluate_script("'a' + 'b'", Some("test.js"), 1)
+            .unwrap();
+        assert_eq!(result.as_string().unwrap(), "ab");
+
+        let exception = ctx.evaluate_script("1 +", None, 1);
+        assert!(exception.is_err());
+
+        let exception = ctx.evaluate_script("throw new Error('oops')", None, 1);
+        assert!(exception.is_err());
+    }
+
+    #[test]
+    fn check_syntax() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        assert!(ctx.check_syntax("let a = 1;\na + 1", None, 1).is_ok());
+
+        // The script isn't evaluated.
+        ctx.check_syntax("var checked = true", None, 1)?;
+        let checked = ctx.evaluate_script("typeof checked", None, 1)?;
+        assert_eq!(checked.as_string()?, "undefined");
+
+        let exception = ctx
+            .check_syntax("let a = 1;\nlet = = ;", Some("broken.js"), 1)
+            .unwrap_err();
+        assert_eq!(exception.name()?, "SyntaxError");
+        assert!(!exception.message().unwrap().to_string().is_empty());
+
+        let error = exception.underlying_value().as_object()?;
+        assert_eq!(error.get_property("line").as_number()?, 2.);
+        assert_eq!(error.get_property("sourceURL").as_string()?, "broken.js");
+
+        Ok(())
+    }
+
+    #[test]
+    fn heap_statistics() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        ctx.collect_garbage();
+        let before = ctx.heap_statistics();
+        assert!(before.heap_size <= before.heap_capacity);
+        assert!(before.global_object_count >= 1);
+
+        ctx.evaluate_script(
+            "globalThis.big = Array.from({ length: 100000 }, (_, i) => ({ i }))",
+            None,
+            1,
+        )?;
+        ctx.collect_garbage();
+        let after = ctx.heap_statistics();
+
+        assert!(after.heap_size > before.heap_size);
+        assert!(after.object_count > before.object_count);
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_script_with_this() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let scope = JSValue::new_from_json(&ctx, r#"{"name": "scoped"}"#)
+            .unwrap()
+            .as_object()?;
+
+        let result = ctx.evaluate_script_with_this("this.name", &scope, None, 1)?;
+        assert_eq!(result.as_string()?, "scoped");
+
+        ctx.evaluate_script_with_this("this.visited = true", &scope, Some("test.js"), 1)?;
+        assert!(scope.get_property("visited").as_boolean());
+
+        // The global object is left untouched.
+        let result = ctx.evaluate_script("typeof this.visited", None, 1)?;
+        assert_eq!(result.as_string()?, "undefined");
+
+        Ok(())
+    }
+
+    #[test]
+    fn scoped() -> Result<(), JSException> {
+        let value = JSContext::scoped(|ctx| -> Result<_, JSException> {
+            ctx.evaluate_script("globalThis.leak = 'only here'", None, 1)?;
+
+            ctx.evaluate_script("[1, 2, 3].length", None, 1)?
+                .as_number()
+        })?;
+        assert_eq!(value, 3.);
+
+        // Each scope has its own context.
+        JSContext::scoped(|ctx| -> Result<_, JSException> {
+            assert!(ctx.get_global("leak")?.is_undefined());
+
+            Ok(())
+        })?;
+
+        // The context is released on panic too.
+        let result = std::panic::catch_unwind(|| JSContext::scoped(|_ctx| panic!("oops")));
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn globals() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        ctx.set_global("ANSWER", 42)?;
+        ctx.set_global("greeting", JSValue::new_string(&ctx, "hello"))?;
+        ctx.set_global("nothing", None::<f64>)?;
+
+        let result = ctx.evaluate_script("`${greeting} ${ANSWER * 2} ${nothing}`", None, 1)?;
+        assert_eq!(result.as_string()?, "hello 84 null");
+
+        ctx.evaluate_script("var fromScript = [1, 2]; globalThis.other = true", None, 1)?;
+        assert!(ctx.get_global("fromScript")?.is_array());
+        assert!(ctx.get_global("other")?.as_boolean());
+        assert!(ctx.get_global("missing")?.is_undefined());
+
+        // Globals are the properties of the global object.
+        assert_eq!(
+            ctx.global_object()?.get_property("ANSWER").as_number()?,
+            42.
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_scripts() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let results = ctx.evaluate_scripts(&[
+            "let count = 1",
+            "throw new Error('second')",
+            "count += 1",
+            "count",
+        ]);
+        assert_eq!(results.len(), 4);
+
+        let mut results = results.into_iter();
+        assert!(results.next().unwrap()?.is_undefined());
+        assert_eq!(
+            results.next().unwrap().unwrap_err().message().unwrap(),
+            "second"
+        );
+        // The scripts after the one throwing still run.
+        assert_eq!(results.next().unwrap()?.as_number()?, 2.);
+        assert_eq!(results.next().unwrap()?.as_number()?, 2.);
+
+        assert!(ctx.evaluate_scripts(&[]).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn evaluate_script_source_url() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let script = "\n\nthrow new Error('oops')";
+
+        let exception = ctx
+            .evaluate_script(script, Some("my-script.js"), 10)
+            .unwrap_err();
+        let error = exception.underlying_value().as_object()?;
+        assert_eq!(error.get_property("sourceURL").as_string()?, "my-script.js");
+        assert_eq!(error.get_property("line").as_number()?, 12.);
+        assert!(exception
+            .stack()
+            .unwrap()
+            .to_string()
+            .contains("my-script.js"));
+
+        // Without a source URL, none is reported.
+        let exception = ctx.evaluate_script(script, None, 1).unwrap_err();
+        let error = exception.underlying_value().as_object()?;
+        assert!(error.get_property("sourceURL").is_undefined());
+        assert_eq!(error.get_property("line").as_number()?, 3.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_array() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let array = ctx.new_array(&[
+            JSValue::new_number(&ctx, 1.),
+            JSValue::new_string(&ctx, "two"),
+            JSValue::new_boolean(&ctx, true),
+        ])?;
+
+        assert!(array.is_array());
+        assert_eq!(array.get_property("length").as_number()?, 3.);
+        assert_eq!(array.get_property_at_index(0).get_type(), JSType::Number);
+        assert_eq!(array.get_property_at_index(1).get_type(), JSType::String);
+        assert_eq!(array.get_property_at_index(2).get_type(), JSType::Boolean);
+        assert_eq!(array.to_json_string(0)?, r#"[1,"two",true]"#);
+
+        let empty = ctx.new_array(&[])?;
+        assert!(empty.is_array());
+        assert_eq!(empty.to_json_string(0)?, "[]");
+
+        // More items than the small array optimization.
+        let items = (0..100)
+            .map(|i| JSValue::new_number(&ctx, f64::from(i)))
+            .collect::<Vec<_>>();
+        let large = ctx.new_array(&items)?;
+        assert_eq!(large.get_property("length").as_number()?, 100.);
+        assert_eq!(large.get_property_at_index(99).as_number()?, 99.);
+
+        // Objects are not arrays.
+        assert!(!ctx.global_object()?.is_array());
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_date() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        // 2009-02-13T23:31:30.123Z
+        let date = ctx.new_date(1_234_567_890_123.)?;
+        assert!(date.is_date());
+        assert_eq!(date.date_milliseconds(), Some(1_234_567_890_123.));
+
+        ctx.global_object()?.set_property("date", (&date).into())?;
+        let iso = ctx.evaluate_script("date.toISOString()", None, 1)?;
+        assert_eq!(iso.as_string()?, "2009-02-13T23:31:30.123Z");
+
+        // Dates have a millisecond precision.
+        let date = ctx.new_date(1.9)?;
+        assert_eq!(date.date_milliseconds(), Some(1.));
+
+        let date = ctx.new_date(f64::NAN)?;
+        assert!(date.is_date());
+        assert!(date.date_milliseconds().unwrap().is_nan());
+
+        let date = ctx.new_date(8.64e15 + 1.)?;
+        assert!(date.date_milliseconds().unwrap().is_nan());
+
+        // Not a date.
+        assert_eq!(ctx.global_object()?.date_milliseconds(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_regexp() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        // One code point, but two UTF-16 code units.
+        let unicode = ctx.new_regexp("^.$", "u")?;
+        assert!(unicode.regexp_test("😀")?);
+        assert!(unicode.regexp_exec("😀")?.is_some());
+
+        let not_unicode = ctx.new_regexp("^.$", "")?;
+        assert!(!not_unicode.regexp_test("😀")?);
+        assert!(not_unicode.regexp_exec("😀")?.is_none());
+
+        let emoji = ctx.new_regexp(r"(\p{Emoji_Presentation}+) (\w+)", "u")?;
+        let matches = emoji.regexp_exec("hello 😀😀 world")?.unwrap();
+        assert!(matches.is_array());
+        assert_eq!(matches.get_property_at_index(0).as_string()?, "😀😀 world");
+        assert_eq!(matches.get_property_at_index(1).as_string()?, "😀😀");
+        assert_eq!(matches.get_property_at_index(2).as_string()?, "world");
+        assert_eq!(matches.get_property("index").as_number()?, 6.);
+
+        let exception = ctx.new_regexp("[", "u").err().unwrap();
+        assert_eq!(exception.name()?, "SyntaxError");
+
+        // Not a regular expression.
+        assert!(ctx.global_object()?.regexp_test("foo").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_promise() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let global_object = ctx.global_object()?;
+
+        let (resolved, resolver) = ctx.new_promise()?;
+        global_object.set_property("resolved", resolved.into())?;
+        ctx.evaluate_script(
+            "globalThis.log = []; resolved.then((v) => log.push('resolved: ' + v))",
+            None,
+            1,
+        )?;
+
+        let (rejected, rejecter) = ctx.new_promise()?;
+        global_object.set_property("rejected", rejected.into())?;
+        ctx.evaluate_script(
+            "rejected.catch((e) => log.push('rejected: ' + e.message))",
+            None,
+            1,
+        )?;
+
+        // Nothing has happened yet, even after a garbage collection.
+        ctx.collect_garbage();
+        assert_eq!(ctx.evaluate_script("log.length", None, 1)?.as_number()?, 0.);
+
+        resolver.resolve(JSValue::new_number(&ctx, 42.))?;
+        rejecter.reject(ctx.evaluate_script("new Error('nope')", None, 1)?)?;
+
+        assert_eq!(
+            ctx.evaluate_script("log.join(', ')", None, 1)?
+                .as_string()?,
+            "resolved: 42, rejected: nope"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn microtasks_run_when_the_outermost_call_returns() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let global_object = ctx.global_object()?;
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        let record = {
+            let log = log.clone();
+
+            ctx.make_function("record", move |ctx, _this, arguments| {
+                log.borrow_mut().push(arguments[0].as_string()?.to_string());
+
+                Ok(JSValue::new_undefined(ctx))
+            })
+        };
+        global_object.set_property("record", record.into())?;
+
+        // The `then` callback runs after the script, but before the
+        // evaluation returns.
+        ctx.evaluate_script(
+            "Promise.resolve('then').then(record); record('sync')",
+            None,
+            1,
+        )?;
+        assert_eq!(*log.borrow(), ["sync", "then"]);
+
+        let (promise, resolver) = ctx.new_promise()?;
+        global_object.set_property("promise", promise.into())?;
+        ctx.evaluate_script("promise.then(record)", None, 1)?;
+        assert_eq!(*log.borrow(), ["sync", "then"]);
+
+        // Resolving from Rust is an outermost call too.
+        resolver.resolve(JSValue::new_string(&ctx, "resolved"))?;
+        assert_eq!(*log.borrow(), ["sync", "then", "resolved"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn collect_garbage() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        for _ in 0..10 {
+            ctx.evaluate_script(
+                "{ const a = []; for (let i = 0; i < 10000; i++) { a.push({ i, s: 'x'.repeat(i % 100) }) } }",
+                None,
+                1,
+            )?;
+            ctx.collect_garbage();
+        }
+
+        assert_eq!(ctx.evaluate_script("1 + 1", None, 1)?.as_number()?, 2.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn report_extra_memory_cost() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let size = 64 * 1024 * 1024;
+
+        let buffer = JSValue::new_array_buffer(&ctx, vec![7u8; size])?;
+        ctx.report_extra_memory_cost(size);
+
+        ctx.global_object()?.set_property("buffer", buffer)?;
+        ctx.collect_garbage();
+
+        let result = ctx.evaluate_script(
+            "const bytes = new Uint8Array(buffer); [bytes.length, bytes[bytes.length - 1]].join()",
+            None,
+            1,
+        )?;
+        assert_eq!(result.as_string()?, format!("{size},7"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_function() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let add = ctx.make_function("add", |ctx, _this, arguments| {
+            let [a, b] = arguments else {
+                return Err(JSValue::new_string(ctx, "expected 2 arguments").into());
+            };
+
+            Ok(JSValue::new_number(ctx, a.as_number()? + b.as_number()?))
+        });
+
+        assert!(add.is_function());
+
+        let global_object = ctx.global_object()?;
+        global_object.set_property("add", add.into())?;
+
+        assert_eq!(ctx.evaluate_script("add(1, 2)", None, 1)?.as_number()?, 3.);
+        assert_eq!(
+            ctx.evaluate_script("add.name", None, 1)?.as_string()?,
+            "add"
+        );
+        assert!(ctx.evaluate_script("add(1)", None, 1).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_function_is_a_function() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let first = ctx.make_function("first", |_ctx, this, arguments| {
+            Ok(this.get_property(arguments[0].as_string()?))
+        });
+        let second = ctx.make_function("second", |ctx, _this, _arguments| {
+            Ok(JSValue::new_number(ctx, 2.))
+        });
+
+        let global_object = ctx.global_object()?;
+        global_object.set_property("first", first.into())?;
+        global_object.set_property("second", second.into())?;
+
+        let result = ctx.evaluate_script("first.call({ a: 1 }, 'a')", None, 1)?;
+        assert_eq!(result.as_number()?, 1.);
+        let result = ctx.evaluate_script("first.apply({ b: 2 }, ['b'])", None, 1)?;
+        assert_eq!(result.as_number()?, 2.);
+        let result = ctx.evaluate_script("second.bind(null)()", None, 1)?;
+        assert_eq!(result.as_number()?, 2.);
+
+        let result = ctx.evaluate_script(
+            "first instanceof Function && Object.getPrototypeOf(first) === Function.prototype",
+            None,
+            1,
+        )?;
+        assert!(result.as_boolean());
+
+        // The functions share their class, but not their prototype chain.
+        ctx.evaluate_script("first.own = 1", None, 1)?;
+        assert!(ctx.evaluate_script("second.own", None, 1)?.is_undefined());
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_function_captures_and_receives_this() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+        let calls = Rc::new(Cell::new(0));
+
+        let counter = {
+            let calls = calls.clone();
+
+            ctx.make_function("counter", move |_ctx, this, _arguments| {
+                calls.set(calls.get() + 1);
+
+                Ok(this.get_property("value"))
+            })
+        };
+
+        let global_object = ctx.global_object()?;
+        global_object.set_property("counter", counter.into())?;
+
+        let result = ctx.evaluate_script("({ value: 'this', counter }).counter()", None, 1)?;
+        assert_eq!(result.as_string()?, "this");
+
+        ctx.evaluate_script("counter(); counter()", None, 1)?;
+        assert_eq!(calls.get(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_function_catches_panics() -> Result<(), JSException> {
+        let ctx = JSContext::new();
+
+        let boom = ctx.make_function("boom", |_ctx, _this, _arguments| panic!("boom"));
+
+        let global_object = ctx.global_object()?;
+        global_object.set_property("boom", boom.into())?;
+
+        let result =
+            ctx.evaluate_script("try { boom(); 'not thrown' } catch (e) { e }", None, 1)?;
+        assert_eq!(result.as_string()?, "Rust function panicked: boom");
+
+        Ok(())
+    }
+
+    #[test]
+    fn global_object() {
+        let ctx = JSContext::new();
+        let global_object = ctx.global_object().unwrap();
+
+        let some_property = global_object.get_property("Array");
+        assert!(!some_property.is_undefined());
+
+        let no_property = global_object.get_property("ThisIsNotAGlobal");
+        assert!(no_property.is_undefined());
     }
 }
@@ -4,8 +4,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, JSClass, JSContext, JSContextGroup};
+use crate::{class, sys, JSClass, JSContext, JSContextGroup};
 use std::ptr;
+use std::time::Duration;
 
 impl JSContextGroup {
     /// Creates a JavaScript context group.
@@ -44,6 +45,62 @@ impl JSContextGroup {
             ))
         }
     }
+
+    /// Limits the CPU time that each script evaluated in the contexts of this
+    /// group may run for, until the limit is
+    /// [cleared](JSContextGroup::clear_execution_time_limit).
+    ///
+    /// A script running for longer than `limit` is terminated, and its
+    /// evaluation returns an exception that JavaScript code can't catch.
+    ///
+    /// The group has a single limit, which
+    /// [`JSContext::evaluate_script_with_timeout()`] and
+    /// [`JSContext::evaluate_script_with_time_limit()`] replace while they
+    /// run, and clear afterwards: the limit set here is gone after any of
+    /// them is called on a context of the group, and must be set again.
+    ///
+    /// This method is part of the [private API](crate#private-api), from
+    /// `JSContextRefPrivate.h`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContextGroup;
+    /// # use std::time::Duration;
+    /// let group = JSContextGroup::new();
+    /// let ctx = group.new_context();
+    ///
+    /// group.set_execution_time_limit(Duration::from_millis(10));
+    /// assert!(ctx.evaluate_script("while (true) {}", None, 1).is_err());
+    ///
+    /// group.clear_execution_time_limit();
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSContext::evaluate_script_with_timeout()`], to limit a single
+    ///   evaluation.
+    pub fn set_execution_time_limit(&self, limit: Duration) {
+        unsafe {
+            sys::JSContextGroupSetExecutionTimeLimit(
+                self.raw,
+                limit.as_secs_f64(),
+                None,
+                ptr::null_mut(),
+            );
+        }
+    }
+
+    /// Removes the CPU time limit of the scripts of this group, so they run
+    /// unbounded again.
+    ///
+    /// It removes a limit set by [`JSContextGroup::set_execution_time_limit()`],
+    /// by [`JSContext::evaluate_script_with_timeout()`], or by another user of
+    /// the group through the C API. Nothing happens if there is no limit.
+    ///
+    /// This method is part of the [private API](crate#private-api), from
+    /// `JSContextRefPrivate.h`.
+    pub fn clear_execution_time_limit(&self) {
+        unsafe { sys::JSContextGroupClearExecutionTimeLimit(self.raw) }
+    }
 }
 
 impl Default for JSContextGroup {
@@ -57,6 +114,40 @@ impl Default for JSContextGroup {
 
 impl Drop for JSContextGroup {
     fn drop(&mut self) {
-        unsafe { sys::JSContextGroupRelease(self.raw) }
+        class::run_deferred_unprotects(self.raw);
+        class::releasing(|| unsafe { sys::JSContextGroupRelease(self.raw) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{JSContextGroup, JSException};
+    use std::time::Duration;
+
+    #[test]
+    fn execution_time_limit() -> Result<(), JSException> {
+        let group = JSContextGroup::new();
+        let ctx = group.new_context();
+        let finite_loop = "let i = 0; while (i < 1e8) { i++ } i";
+
+        group.set_execution_time_limit(Duration::from_millis(10));
+
+        // The termination can't be caught.
+        let result = ctx.evaluate_script("try { while (true) {} } catch { 'caught' }", None, 1);
+        assert!(result.is_err());
+        // It applies to every context of the group.
+        assert!(group
+            .new_context()
+            .evaluate_script("while (true) {}", None, 1)
+            .is_err());
+
+        group.clear_execution_time_limit();
+
+        assert_eq!(ctx.evaluate_script(finite_loop, None, 1)?.as_number()?, 1e8);
+
+        // Clearing again does nothing.
+        group.clear_execution_time_limit();
+
+        Ok(())
     }
 }
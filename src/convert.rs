@@ -0,0 +1,296 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{JSContext, JSException, JSObject, JSString, JSValue};
+
+/// A Rust value that can be converted into a [`JSValue`].
+///
+/// Creating a `JSValue` needs a context, hence this trait rather than
+/// `From` implementations. It's implemented for:
+///
+/// * `bool`, converted to a boolean,
+/// * the numeric types which fit in an `f64`, converted to a number;
+///   `i64` and `u64` are not, as they may lose precision,
+/// * `&str`, `String`, [`JSString`] and `&JSString`, converted to a string,
+/// * `Option<T>`, where `None` is converted to `null`,
+/// * `()`, converted to `undefined`,
+/// * [`JSValue`] and [`JSObject`], which are kept as is.
+///
+/// See [`JSContext::value_from()`].
+///
+/// ```rust
+/// # use javascriptcore::{IntoJSValue, JSContext};
+/// let ctx = JSContext::default();
+///
+/// assert!(true.into_js_value(&ctx).as_boolean());
+/// assert_eq!(42u8.into_js_value(&ctx).as_number().unwrap(), 42.);
+/// assert_eq!("hello".into_js_value(&ctx).as_string().unwrap(), "hello");
+/// assert!(None::<f64>.into_js_value(&ctx).is_null());
+/// ```
+pub trait IntoJSValue {
+    /// Converts `self` into a [`JSValue`] in the context `ctx`.
+    fn into_js_value(self, ctx: &JSContext) -> JSValue;
+}
+
+impl IntoJSValue for bool {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_boolean(ctx, self)
+    }
+}
+
+macro_rules! into_js_number {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IntoJSValue for $ty {
+                fn into_js_value(self, ctx: &JSContext) -> JSValue {
+                    JSValue::new_number(ctx, f64::from(self))
+                }
+            }
+        )*
+    };
+}
+
+into_js_number!(i8, i16, i32, u8, u16, u32, f32, f64);
+
+impl IntoJSValue for &str {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_string(ctx, self)
+    }
+}
+
+impl IntoJSValue for String {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_string(ctx, self)
+    }
+}
+
+impl IntoJSValue for JSString {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_string(ctx, self)
+    }
+}
+
+impl IntoJSValue for &JSString {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_string(ctx, self.clone())
+    }
+}
+
+impl<T: IntoJSValue> IntoJSValue for Option<T> {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        match self {
+            Some(value) => value.into_js_value(ctx),
+            None => JSValue::new_null(ctx),
+        }
+    }
+}
+
+impl IntoJSValue for () {
+    fn into_js_value(self, ctx: &JSContext) -> JSValue {
+        JSValue::new_undefined(ctx)
+    }
+}
+
+impl IntoJSValue for JSValue {
+    fn into_js_value(self, _ctx: &JSContext) -> JSValue {
+        self
+    }
+}
+
+impl IntoJSValue for JSObject {
+    fn into_js_value(self, _ctx: &JSContext) -> JSValue {
+        self.into()
+    }
+}
+
+/// A Rust value that can be extracted from a [`JSValue`].
+///
+/// Contrary to [`JSValue::as_number()`] and its siblings, no JavaScript
+/// conversion happens: extracting a value of the wrong type returns an
+/// [exception](JSException). It's implemented for:
+///
+/// * `bool`, from a boolean,
+/// * `f64`, from a number,
+/// * `String` and [`JSString`], from a string,
+/// * [`JSObject`], from an object,
+/// * `Option<T>`, where `null` and `undefined` are extracted as `None`, and
+///   other values as `T`, so a value of the wrong type is still an error.
+///
+/// See [`JSValue::extract()`].
+///
+/// ```rust
+/// # use javascriptcore::{FromJSValue, JSContext, JSValue};
+/// let ctx = JSContext::default();
+///
+/// assert_eq!(f64::from_js_value(&JSValue::new_number(&ctx, 1.)).unwrap(), 1.);
+/// assert!(f64::from_js_value(&JSValue::new_string(&ctx, "1")).is_err());
+/// assert_eq!(Option::<f64>::from_js_value(&JSValue::new_null(&ctx)).unwrap(), None);
+/// ```
+pub trait FromJSValue: Sized {
+    /// Extracts a `Self` from `value`.
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException>;
+}
+
+impl FromJSValue for bool {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        value
+            .as_bool()
+            .ok_or_else(|| type_error(value, "Value is not a boolean"))
+    }
+}
+
+impl FromJSValue for f64 {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        value
+            .as_f64()
+            .ok_or_else(|| type_error(value, "Value is not a number"))
+    }
+}
+
+impl FromJSValue for JSString {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        if !value.is_string() {
+            return Err(type_error(value, "Value is not a string"));
+        }
+
+        value.as_string()
+    }
+}
+
+impl FromJSValue for String {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        JSString::from_js_value(value).map(|string| string.to_string())
+    }
+}
+
+impl FromJSValue for JSObject {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        if !value.is_object() {
+            return Err(type_error(value, "Value is not an object"));
+        }
+
+        value.as_object()
+    }
+}
+
+impl<T: FromJSValue> FromJSValue for Option<T> {
+    fn from_js_value(value: &JSValue) -> Result<Self, JSException> {
+        if value.is_nullish() {
+            Ok(None)
+        } else {
+            T::from_js_value(value).map(Some)
+        }
+    }
+}
+
+fn type_error(value: &JSValue, message: &str) -> JSException {
+    JSValue::new_string_inner(value.ctx, message).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromJSValue, IntoJSValue};
+    use crate::{JSContext, JSException, JSObject, JSString, JSValue};
+
+    #[test]
+    fn into_js_value() {
+        let ctx = JSContext::default();
+
+        assert!(false.into_js_value(&ctx).is_boolean());
+        assert_eq!((-1i8).into_js_value(&ctx).as_number().unwrap(), -1.);
+        assert_eq!(
+            u32::MAX.into_js_value(&ctx).as_number().unwrap(),
+            4294967295.
+        );
+        assert_eq!(0.5f32.into_js_value(&ctx).as_number().unwrap(), 0.5);
+        assert_eq!(
+            String::from("a").into_js_value(&ctx).as_string().unwrap(),
+            "a"
+        );
+        assert_eq!(
+            JSString::from("b").into_js_value(&ctx).as_string().unwrap(),
+            "b"
+        );
+        assert_eq!(Some(1.).into_js_value(&ctx).as_number().unwrap(), 1.);
+        assert!(None::<&str>.into_js_value(&ctx).is_null());
+        assert!(().into_js_value(&ctx).is_undefined());
+    }
+
+    #[test]
+    fn call_with_mixed_arguments() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let describe = ctx
+            .evaluate_script(
+                "(name, age, admin, team) => `${name} ${age} ${admin} ${team}`",
+                None,
+                1,
+            )?
+            .as_object()?;
+
+        let result = describe.call_as_function(
+            None,
+            &[
+                ctx.value_from("Ada"),
+                ctx.value_from(36),
+                ctx.value_from(true),
+                ctx.value_from(None::<&str>),
+            ],
+        )?;
+
+        assert_eq!(result.as_string()?, "Ada 36 true null");
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_js_value() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let number = JSValue::new_number(&ctx, 1.5);
+        let string = JSValue::new_string(&ctx, "abc");
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap();
+
+        assert!(bool::from_js_value(&JSValue::new_boolean(&ctx, true))?);
+        assert!(bool::from_js_value(&number).is_err());
+
+        assert_eq!(f64::from_js_value(&number)?, 1.5);
+        assert!(f64::from_js_value(&string).is_err());
+
+        assert_eq!(String::from_js_value(&string)?, "abc");
+        assert_eq!(JSString::from_js_value(&string)?, "abc");
+        assert!(String::from_js_value(&number).is_err());
+
+        assert!(JSObject::from_js_value(&object).is_ok());
+        assert!(JSObject::from_js_value(&string).is_err());
+
+        let x: f64 = number.extract()?;
+        assert_eq!(x, 1.5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn option_from_js_value() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        assert_eq!(
+            Option::<f64>::from_js_value(&JSValue::new_null(&ctx))?,
+            None
+        );
+        assert_eq!(
+            Option::<String>::from_js_value(&JSValue::new_undefined(&ctx))?,
+            None
+        );
+        assert_eq!(
+            Option::<f64>::from_js_value(&JSValue::new_number(&ctx, 2.))?,
+            Some(2.)
+        );
+
+        // A value of the wrong type isn't `None`.
+        assert!(Option::<f64>::from_js_value(&JSValue::new_string(&ctx, "2")).is_err());
+
+        Ok(())
+    }
+}
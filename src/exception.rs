@@ -4,9 +4,9 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use std::{error, fmt};
+use std::{error, fmt, ptr};
 
-use crate::{sys, JSException, JSString, JSValue};
+use crate::{sys, JSContext, JSException, JSString, JSValue};
 
 impl JSException {
     /// Return the underlying value backing the exception.
@@ -14,17 +14,57 @@ impl JSException {
         &self.value
     }
 
+    /// Return a new [`JSValue`] pointing to the underlying value backing the
+    /// exception.
+    pub fn to_value(&self) -> JSValue {
+        unsafe { JSValue::from_raw(self.value.ctx, self.value.raw) }
+    }
+
     /// Return the name of the exception. This is the value of the `name`
     /// property on the exception object.
     pub fn name(&self) -> Result<JSString, JSException> {
         self.value.as_object()?.get_property("name").as_string()
     }
+
+    /// Return the message of the exception. This is the value of the
+    /// `message` property on the exception object.
+    ///
+    /// Returns `None` if the exception isn't an object, e.g. with
+    /// `throw "oops"`, or if it has no `message` property.
+    pub fn message(&self) -> Option<JSString> {
+        self.string_property("message")
+    }
+
+    /// Return the stack trace of the exception. This is the value of the
+    /// `stack` property on the exception object.
+    ///
+    /// Returns `None` if the exception isn't an object, e.g. with
+    /// `throw "oops"`, or if it has no `stack` property.
+    pub fn stack(&self) -> Option<JSString> {
+        self.string_property("stack")
+    }
+
+    fn string_property(&self, name: &str) -> Option<JSString> {
+        if !self.value.is_object() {
+            return None;
+        }
+
+        let property = self.value.as_object().ok()?.try_get_property(name).ok()?;
+
+        if property.is_undefined() {
+            None
+        } else {
+            property.as_string().ok()
+        }
+    }
 }
 
+/// An exception is displayed as its string conversion, e.g. `TypeError: boom`
+/// for `new TypeError("boom")`.
 impl fmt::Display for JSException {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.underlying_value().as_string() {
-            Ok(string) => write!(formatter, "JSException (interpreted as string): {string}"),
+            Ok(string) => write!(formatter, "{string}"),
             Err(_) => write!(formatter, "{self:?}"),
         }
     }
@@ -43,3 +83,162 @@ impl From<JSException> for sys::JSValueRef {
         value.value.raw
     }
 }
+
+/// A scope recording the exceptions thrown by raw JavaScriptCore calls.
+///
+/// It is created by [`JSContext::with_exception_scope()`]. Each call to a
+/// `sys` function taking an `exception` out-pointer goes through
+/// [`JSExceptionScope::call()`], which turns the exception into an `Err`, and
+/// records it. The first recorded exception is returned by the scope, even if
+/// the `Err` was ignored.
+pub struct JSExceptionScope<'c> {
+    ctx: &'c JSContext,
+    exception: sys::JSValueRef,
+}
+
+impl<'c> JSExceptionScope<'c> {
+    /// Create a new [`Self`], with no exception recorded.
+    pub(crate) fn new(ctx: &'c JSContext) -> Self {
+        Self {
+            ctx,
+            exception: ptr::null_mut(),
+        }
+    }
+
+    /// Return the context of this scope.
+    pub const fn context(&self) -> &'c JSContext {
+        self.ctx
+    }
+
+    /// Calls `function` with the context and an `exception` out-pointer to
+    /// pass to a raw JavaScriptCore call.
+    ///
+    /// Returns either the result of `function`, or the [exception](JSException)
+    /// it stored in the out-pointer.
+    pub fn call<F, R>(&mut self, function: F) -> Result<R, JSException>
+    where
+        F: FnOnce(sys::JSContextRef, *mut sys::JSValueRef) -> R,
+    {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let result = function(self.ctx.raw, &mut exception);
+
+        if exception.is_null() {
+            return Ok(result);
+        }
+
+        if self.exception.is_null() {
+            self.exception = exception;
+        }
+
+        Err(unsafe { JSValue::from_raw(self.ctx.raw, exception) }.into())
+    }
+
+    /// Returns the first recorded exception, if any, or `result`.
+    pub(crate) fn finish<T>(self, result: Result<T, JSException>) -> Result<T, JSException> {
+        if self.exception.is_null() {
+            result
+        } else {
+            Err(unsafe { JSValue::from_raw(self.ctx.raw, self.exception) }.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{evaluate_script, sys, JSContext, JSException, JSString, JSValue};
+    use std::ptr;
+
+    #[test]
+    fn error_fields() {
+        let ctx = JSContext::default();
+
+        let exception =
+            evaluate_script(&ctx, "throw new TypeError(\"boom\")", None, "foo.js", 1).unwrap_err();
+
+        assert_eq!(exception.name().unwrap(), "TypeError");
+        assert_eq!(exception.message().unwrap(), "boom");
+        assert!(exception.stack().is_some());
+        assert!(exception.to_value().is_object());
+        assert_eq!(exception.to_string(), "TypeError: boom");
+    }
+
+    #[test]
+    fn primitive_fields() {
+        let ctx = JSContext::default();
+
+        let exception = evaluate_script(&ctx, "throw 'oops'", None, "foo.js", 1).unwrap_err();
+
+        assert!(exception.message().is_none());
+        assert!(exception.stack().is_none());
+        assert!(exception.to_value().is_string());
+        assert_eq!(exception.to_string(), "oops");
+    }
+
+    #[test]
+    fn exception_scope() {
+        let ctx = JSContext::default();
+        let script = JSString::from("throw new Error('boom')");
+
+        let result = ctx.with_exception_scope(|scope| {
+            let value = scope.call(|ctx, exception| unsafe {
+                sys::JSEvaluateScript(
+                    ctx,
+                    JSString::from("21 * 2").raw,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    1,
+                    exception,
+                )
+            })?;
+
+            Ok(unsafe { JSValue::from_raw(scope.context().raw, value) })
+        });
+        assert_eq!(result.unwrap().as_number().unwrap(), 42.);
+
+        let result = ctx.with_exception_scope(|scope| {
+            scope.call(|ctx, exception| unsafe {
+                sys::JSEvaluateScript(
+                    ctx,
+                    script.raw,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    1,
+                    exception,
+                )
+            })?;
+
+            Ok(())
+        });
+        assert_eq!(result.unwrap_err().to_string(), "Error: boom");
+
+        // An ignored exception is still returned.
+        let result = ctx.with_exception_scope(|scope| {
+            let _ = scope.call(|ctx, exception| unsafe {
+                sys::JSEvaluateScript(
+                    ctx,
+                    script.raw,
+                    ptr::null_mut(),
+                    ptr::null_mut(),
+                    1,
+                    exception,
+                )
+            });
+
+            Ok(())
+        });
+        assert_eq!(result.unwrap_err().to_string(), "Error: boom");
+    }
+
+    #[test]
+    fn question_mark() {
+        fn run(ctx: &JSContext) -> Result<f64, JSException> {
+            evaluate_script(ctx, "null.foo", None, "foo.js", 1)?.as_number()
+        }
+
+        let ctx = JSContext::default();
+        let exception = run(&ctx).unwrap_err();
+
+        assert_eq!(exception.name().unwrap(), "TypeError");
+        assert!(exception.to_string().starts_with("TypeError: "));
+    }
+}
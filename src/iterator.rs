@@ -0,0 +1,103 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{class, JSException, JSObject, JSValue, Protected};
+
+/// An iterator over the values of a JavaScript iterable, like an array, a
+/// `Map`, a `Set` or a generator.
+///
+/// It is created by [`JSObject::iter()`], and follows the JavaScript
+/// iteration protocol: each step calls the `next` method of the JavaScript
+/// iterator, and yields its `value` until it is `done`. A step yields an
+/// [exception](JSException) if one was thrown, after which the iteration
+/// stops.
+///
+/// If the iteration is stopped early, i.e. the `JSIterator` is dropped before
+/// it is done, the `return` method of the JavaScript iterator is called, as a
+/// `for...of` loop does. This lets generators run their `finally` blocks. This
+/// isn't done when the `JSIterator` is dropped by the finalizer of an object,
+/// as part of its [private data](crate::JSClass::new_object_with_private_data).
+///
+/// The JavaScript iterator is [protected](Protected) from garbage collection
+/// until the `JSIterator` is dropped.
+pub struct JSIterator {
+    iterator: Protected<JSObject>,
+    next: Protected<JSObject>,
+    done: bool,
+}
+
+impl JSIterator {
+    /// Create a new [`Self`] from a JavaScript iterator and its `next`
+    /// method.
+    pub(crate) fn new(iterator: JSObject, next: JSObject) -> Self {
+        Self {
+            iterator: Protected::new(iterator),
+            next: Protected::new(next),
+            done: false,
+        }
+    }
+
+    /// Calls `next`, and returns the `value` of its result, or `None` if the
+    /// iteration is done.
+    fn step(&self) -> Result<Option<JSValue>, JSException> {
+        let result = self.next.call_as_function(Some(&self.iterator), &[])?;
+
+        if !result.is_object() {
+            return Err(
+                JSValue::new_string_inner(result.ctx, "Iterator result is not an object").into(),
+            );
+        }
+
+        let result = result.as_object()?;
+
+        if result.try_get_property("done")?.as_boolean() {
+            return Ok(None);
+        }
+
+        result.try_get_property("value").map(Some)
+    }
+}
+
+impl Iterator for JSIterator {
+    type Item = Result<JSValue, JSException>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let step = self.step().transpose();
+
+        if !matches!(step, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        step
+    }
+}
+
+impl Drop for JSIterator {
+    fn drop(&mut self) {
+        // JavaScriptCore can't be called from a finalizer, so `return` isn't
+        // called. The fields still defer unprotecting their values.
+        if self.done || class::is_finalizing() {
+            return;
+        }
+
+        // Errors can't be reported from here, and `return` is optional.
+        let Ok(method) = self
+            .iterator
+            .try_get_property("return")
+            .and_then(|method| method.as_object())
+        else {
+            return;
+        };
+
+        if method.is_function() {
+            let _ = method.call_as_function(Some(&self.iterator), &[]);
+        }
+    }
+}
@@ -8,6 +8,24 @@
 //!
 //! Evaluate JavaScript programs from within an app, and support
 //! JavaScript scripting of your app.
+//!
+//! # Thread safety
+//!
+//! A JavaScript context, and everything created from it, must be used from
+//! one thread at a time. Since a [`JSValue`] doesn't borrow its
+//! [`JSContext`], nothing ties the values to the thread of their context
+//! but their own types: none of the types of this crate are `Send` or
+//! `Sync`, so they can't be accidentally shared across threads. To run
+//! JavaScript on several threads, create a context on each of them.
+//!
+//! # Private API
+//!
+//! A few items are built on functions which are private to JavaScriptCore,
+//! i.e. declared in its `*Private.h` headers rather than in its public ones.
+//! Its builds export them all the same, so they link like the rest of the
+//! C API, but they aren't covered by the compatibility guarantees of the
+//! public API: a future version of JavaScriptCore may change or remove them.
+//! These items name the header they rely on.
 
 #![warn(clippy::doc_markdown, missing_docs)]
 #![deny(
@@ -25,18 +43,42 @@ pub use rusty_jsc as sys;
 
 mod base;
 mod class;
+mod collection;
 mod context;
 mod contextgroup;
+mod convert;
 mod exception;
+mod iterator;
 mod object;
+mod promise;
+mod protected;
+mod script;
+#[cfg(feature = "serde")]
+mod serialization;
 mod string;
 mod typed_array;
 mod value;
+mod weak;
 
+#[cfg(feature = "serde")]
+pub use crate::serialization::{from_jsvalue, to_jsvalue, JSSerdeError};
 pub use crate::sys::{JSType, JSTypedArrayType};
 pub use crate::{
     base::{check_script_syntax, evaluate_script, garbage_collect},
     class::JSClassBuilder,
+    collection::{JSMap, JSSet},
+    context::{JSEvaluationError, JSHeapStatistics},
+    convert::{FromJSValue, IntoJSValue},
+    exception::JSExceptionScope,
+    iterator::JSIterator,
+    object::{JSObjectPropertyNameIter, JSPropertyDescriptor},
+    promise::JSPromiseResolver,
+    protected::Protected,
+    script::JSScript,
+    string::{JSStringBuf, JSStringCache, JSStringChars, JSStringParseError},
+    typed_array::JSTypedArrayElement,
+    value::{JSNumberDisplay, JSPrimitiveHint},
+    weak::WeakJSObject,
 };
 
 /// A JavaScript class.
@@ -52,6 +94,46 @@ pub struct JSClass {
 /// A JavaScript execution context.
 ///
 /// Holds the global object and other execution state.
+///
+/// The pending microtasks, e.g. the `then` callbacks of settled promises, run
+/// each time the outermost call into JavaScriptCore returns, like
+/// [`JSContext::evaluate_script()`], [`JSObject::call_as_function()`] or
+/// [`JSPromiseResolver::resolve()`]. The C API has no function to run them
+/// otherwise, and doesn't need one: they have already run by the time Rust
+/// code regains control. Called from a JavaScript callback, such a call
+/// isn't the outermost one, so the microtasks wait for the outermost call to
+/// return.
+///
+/// ```rust
+/// # use javascriptcore::JSContext;
+/// let ctx = JSContext::new();
+///
+/// // Microtasks don't run during the evaluation itself…
+/// let script = "var log = []; Promise.resolve().then(() => log.push(1)); log.length";
+/// let result = ctx.evaluate_script(script, None, 1).unwrap();
+/// assert_eq!(result.as_number().unwrap(), 0.);
+///
+/// // … but before it returns.
+/// let result = ctx.evaluate_script("log.length", None, 1).unwrap();
+/// assert_eq!(result.as_number().unwrap(), 1.);
+/// ```
+///
+/// A context is neither `Send` nor `Sync`, see [the crate
+/// documentation](crate#thread-safety):
+///
+/// ```compile_fail
+/// # use javascriptcore::JSContext;
+/// fn assert_send<T: Send>(_: T) {}
+///
+/// assert_send(JSContext::default());
+/// ```
+///
+/// ```compile_fail
+/// # use javascriptcore::JSContext;
+/// fn assert_sync<T: Sync>(_: &T) {}
+///
+/// assert_sync(&JSContext::default());
+/// ```
 pub struct JSContext {
     raw: sys::JSGlobalContextRef,
 }
@@ -149,7 +231,12 @@ pub struct JSTypedArray {
 /// * [`JSValue::new_boolean()`]
 /// * [`JSValue::new_number()`]
 /// * [`JSValue::new_string()`]
+/// * [`JSValue::new_big_int_from_i64()`]
+/// * [`JSValue::new_big_int_from_string()`]
+/// * [`JSValue::new_typed_array()`]
 /// * [`JSValue::new_typed_array_with_bytes()`]
+/// * [`JSValue::new_typed_array_with_vec()`]
+/// * [`JSValue::new_array_buffer()`]
 /// * [`JSValue::new_function()`]
 /// * [`JSValue::new_from_json()`]
 ///
@@ -157,6 +244,8 @@ pub struct JSTypedArray {
 ///
 /// * [`JSValue::new_from_json()`]
 /// * [`JSValue::to_json_string()`]
+/// * [`JSValue::new_from_json_with_reviver()`]
+/// * [`JSValue::to_json_string_with_replacer()`]
 ///
 /// # Retrieving Rust values
 ///
@@ -165,7 +254,26 @@ pub struct JSTypedArray {
 /// * [`JSValue::as_object()`]
 /// * [`JSValue::as_string()`]
 /// * [`JSValue::as_typed_array()`]
-#[derive(Debug)]
+/// * [`JSValue::as_map()`]
+/// * [`JSValue::as_set()`]
+///
+/// Or, without any conversion:
+///
+/// * [`JSValue::as_bool()`]
+/// * [`JSValue::as_f64()`]
+/// * [`JSValue::as_i64()`]
+/// * [`JSValue::as_js_string()`]
+/// * [`JSValue::extract()`]
+///
+/// A value is neither `Send` nor `Sync`, like its context:
+///
+/// ```compile_fail
+/// # use javascriptcore::{JSContext, JSValue};
+/// let ctx = JSContext::default();
+/// let value = JSValue::new_number(&ctx, 1.);
+///
+/// std::thread::spawn(move || value.as_number());
+/// ```
 pub struct JSValue {
     raw: sys::JSValueRef,
     ctx: sys::JSContextRef,
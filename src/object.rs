@@ -4,16 +4,23 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::{sys, JSException, JSObject, JSString, JSValue};
+use crate::{
+    class::{self, PrivateData},
+    sys, JSException, JSIterator, JSObject, JSString, JSValue,
+};
 use std::ops::Deref;
-use std::ptr;
+use std::{ptr, slice};
 
 impl JSObject {
     /// Create a new [`Self`] from its raw pointer directly.
     ///
+    /// It can be used to receive objects from other code using
+    /// JavaScriptCore directly, see [`JSObject::as_raw()`].
+    ///
     /// # Safety
     ///
-    /// Ensure `raw` is valid.
+    /// Ensure `raw` is valid, and is an object of the context `ctx`, with
+    /// the same requirements as [`JSValue::from_raw()`].
     pub const unsafe fn from_raw(ctx: sys::JSContextRef, raw: sys::JSObjectRef) -> Self {
         Self {
             raw,
@@ -21,8 +28,188 @@ impl JSObject {
         }
     }
 
+    /// Returns the raw pointer of this object, e.g. to pass it to other code
+    /// using JavaScriptCore directly.
+    ///
+    /// The pointer is valid as long as the object is alive, see the safety
+    /// section of [`JSValue::from_raw()`]. The private data of an object of a
+    /// [`JSClass`](crate::JSClass) must not be changed through it, e.g. with
+    /// [`sys::JSObjectSetPrivate()`], since this crate owns it.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{sys, JSContext, JSObject, JSValue};
+    /// let ctx = JSContext::default();
+    /// let object = JSValue::new_from_json(&ctx, r#"{"id": 1}"#).unwrap().as_object().unwrap();
+    ///
+    /// let raw: sys::JSObjectRef = object.as_raw();
+    /// assert!(!unsafe { sys::JSObjectIsFunction(ctx.as_raw(), raw) });
+    ///
+    /// let object = unsafe { JSObject::from_raw(ctx.as_raw(), raw) };
+    /// assert_eq!(object.get_property("id").as_number().unwrap(), 1.);
+    /// ```
+    pub const fn as_raw(&self) -> sys::JSObjectRef {
+        self.raw
+    }
+
+    /// Gets the private data of this object, if it has some of type `T`.
+    ///
+    /// Returns `None` if the object has no private data, or if it isn't of
+    /// type `T`. Private data is set when creating an object with
+    /// [`JSClass::new_object_with_private_data()`].
+    ///
+    /// Only the private data of objects of a [`JSClass`] of this crate is
+    /// read: private data set by other code, e.g. with
+    /// [`sys::JSObjectSetPrivate()`] on an object of another class, is never
+    /// returned, since its type is unknown.
+    ///
+    /// ```rust
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let class = JSClass::builder(&ctx, "Foo").unwrap().build().unwrap();
+    /// let object = class.new_object_with_private_data(42u32);
+    ///
+    /// assert_eq!(object.private_data::<u32>(), Some(&42));
+    /// assert_eq!(object.private_data::<i32>(), None);
+    /// ```
+    ///
+    /// [`JSClass`]: crate::JSClass
+    /// [`JSClass::new_object_with_private_data()`]: crate::JSClass::new_object_with_private_data
+    pub fn private_data<T: 'static>(&self) -> Option<&T> {
+        let data = unsafe { class::get_private_data(self.value.ctx, self.raw) };
+
+        if data.is_null() {
+            None
+        } else {
+            unsafe { &*data }.downcast_ref()
+        }
+    }
+
+    /// Gets a mutable reference to the private data of this object, if it has
+    /// some of type `T`.
+    ///
+    /// Prefer [`JSObject::private_data()`] with a type providing interior
+    /// mutability, like `Cell` or `RefCell`.
+    ///
+    /// # Safety
+    ///
+    /// Several `JSObject` can point to the same JavaScript object, e.g. when
+    /// getting the same property twice. Ensure no other reference to the
+    /// private data is alive while the returned one is used.
+    pub unsafe fn private_data_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        let data = unsafe { class::get_private_data(self.value.ctx, self.raw) };
+
+        if data.is_null() {
+            None
+        } else {
+            unsafe { &mut *data }.downcast_mut()
+        }
+    }
+
+    /// Sets the private data of this object, if it doesn't have some yet.
+    ///
+    /// The type of the data is stored alongside it, so that
+    /// [`JSObject::private_data()`] only returns it for the same type `T`. The
+    /// data is dropped when the object is garbage collected.
+    ///
+    /// Only objects of a [`JSClass`] can have private data. Returns `data`
+    /// back as an error if the object isn't of a class of this crate, or if
+    /// it already has private data: the existing data is never replaced,
+    /// since references to it may still be alive.
+    ///
+    /// ```rust
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let class = JSClass::builder(&ctx, "Foo").unwrap().build().unwrap();
+    /// let object = class.new_object();
+    ///
+    /// assert!(object.set_private_data("foo").is_ok());
+    /// assert_eq!(object.private_data::<&str>(), Some(&"foo"));
+    ///
+    /// assert_eq!(object.set_private_data("bar"), Err("bar"));
+    /// ```
+    ///
+    /// [`JSClass`]: crate::JSClass
+    pub fn set_private_data<T: 'static>(&self, data: T) -> Result<(), T> {
+        // Objects of other classes wouldn't drop the data, or would drop it
+        // as another type.
+        let private_data_class = class::private_data_class();
+
+        if !unsafe { sys::JSValueIsObjectOfClass(self.value.ctx, self.raw, private_data_class) }
+            || !unsafe { sys::JSObjectGetPrivate(self.raw) }.is_null()
+        {
+            return Err(data);
+        }
+
+        let data = class::into_private_data(data);
+
+        if unsafe { sys::JSObjectSetPrivate(self.raw, data) } {
+            Ok(())
+        } else {
+            let data = unsafe { Box::from_raw(data.cast::<PrivateData>()) };
+
+            Err(*data.downcast().expect("private data has the type `T`"))
+        }
+    }
+
+    /// Gets an object's prototype.
+    ///
+    /// Returns a [`JSValue`] that is the object's prototype, or `null` if
+    /// the object has none.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let o = JSValue::new_from_json(&ctx, "[]").unwrap().as_object().unwrap();
+    ///
+    /// assert!(o.prototype().as_object().unwrap().has_property("push"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::set_prototype()`]
+    pub fn prototype(&self) -> JSValue {
+        unsafe {
+            JSValue::from_raw(
+                self.value.ctx,
+                sys::JSObjectGetPrototype(self.value.ctx, self.raw),
+            )
+        }
+    }
+
+    /// Sets an object's prototype.
+    ///
+    /// * `prototype`: A [`JSValue`] to set as the object's prototype. It
+    ///   must be an `object` or `null`, otherwise it is ignored. Setting it to
+    ///   `null` creates an object without a prototype, which doesn't inherit
+    ///   anything, not even the `Object.prototype` methods like `toString`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let o = JSValue::new_from_json(&ctx, "{}").unwrap().as_object().unwrap();
+    ///
+    /// o.set_prototype(&JSValue::new_null(&ctx));
+    /// assert!(o.prototype().is_null());
+    /// assert!(!o.has_property("toString"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::prototype()`]
+    pub fn set_prototype(&self, prototype: &JSValue) {
+        unsafe { sys::JSObjectSetPrototype(self.value.ctx, self.raw, prototype.raw) }
+    }
+
     /// Gets an iterator over the names of an object's enumerable properties.
     ///
+    /// Like a `for...in` loop, this includes the enumerable properties
+    /// inherited from the prototype chain. Use [`JSObject::keys()`] to get
+    /// the own enumerable properties only.
+    ///
+    /// The names are copied once, when this method is called. Each yielded
+    /// [`JSString`] is retained, so it stays valid after the iterator is
+    /// dropped.
+    ///
     /// ```
     /// # use javascriptcore::{JSContext, JSObject, JSString, JSValue};
     /// let ctx = JSContext::default();
@@ -41,6 +228,147 @@ impl JSObject {
         }
     }
 
+    /// Gets the names of an object's own enumerable properties, like
+    /// `Object.keys()` in JavaScript.
+    ///
+    /// Contrary to [`JSObject::property_names()`], inherited properties are
+    /// skipped. Non-enumerable properties, e.g. the ones defined with
+    /// [`JSPropertyDescriptor::dont_enum()`], and symbol-keyed properties
+    /// are skipped too.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. by a
+    /// `Proxy`.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let o = JSValue::new_from_json(&ctx, r#"{"a": 1, "b": 2}"#).unwrap().as_object().unwrap();
+    ///
+    /// assert_eq!(o.keys().unwrap(), ["a", "b"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::own_property_names()`]
+    /// * [`JSObject::values()`]
+    pub fn keys(&self) -> Result<Vec<JSString>, JSException> {
+        self.call_object_function("keys", &[self.into()])?
+            .as_object()?
+            .iter()?
+            .map(|key| key?.as_string())
+            .collect()
+    }
+
+    /// Gets the names of all an object's own properties, enumerable or not,
+    /// like `Object.getOwnPropertyNames()` in JavaScript.
+    ///
+    /// Contrary to [`JSObject::keys()`], non-enumerable properties are
+    /// included, e.g. the `length` of arrays. Inherited and symbol-keyed
+    /// properties are still skipped.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. by a
+    /// `Proxy`.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let o = JSValue::new_from_json(&ctx, "[true]").unwrap().as_object().unwrap();
+    ///
+    /// assert_eq!(o.keys().unwrap(), ["0"]);
+    /// assert_eq!(o.own_property_names().unwrap(), ["0", "length"]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::keys()`]
+    /// * [`JSObject::property_names()`]
+    pub fn own_property_names(&self) -> Result<Vec<JSString>, JSException> {
+        self.call_object_function("getOwnPropertyNames", &[self.into()])?
+            .as_object()?
+            .iter()?
+            .map(|name| name?.as_string())
+            .collect()
+    }
+
+    /// Gets the values of an object's own enumerable properties, like
+    /// `Object.values()` in JavaScript.
+    ///
+    /// The values are in the same order as the names returned by
+    /// [`JSObject::keys()`], and follow the same enumerability rules.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. by a
+    /// getter.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let o = JSValue::new_from_json(&ctx, r#"{"a": 1, "b": 2}"#).unwrap().as_object().unwrap();
+    /// let values = o.values().unwrap();
+    ///
+    /// assert_eq!(values.len(), 2);
+    /// assert_eq!(values[1].as_number().unwrap(), 2.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::keys()`]
+    pub fn values(&self) -> Result<Vec<JSValue>, JSException> {
+        self.call_object_function("values", &[self.into()])?
+            .as_object()?
+            .iter()?
+            .collect()
+    }
+
+    /// Gets an iterator over the values of an iterable object, like an
+    /// array, a `Map`, a `Set` or a generator.
+    ///
+    /// This calls the `Symbol.iterator` method of the object, as a
+    /// `for...of` loop does. Returns an [exception](JSException) if one was
+    /// thrown, or if the object isn't iterable.
+    ///
+    /// ```
+    /// # use javascriptcore::{evaluate_script, JSContext};
+    /// let ctx = JSContext::default();
+    /// let set = evaluate_script(&ctx, "new Set([1, 2, 2, 3])", None, "foo.js", 1)
+    ///     .unwrap()
+    ///     .as_object()
+    ///     .unwrap();
+    ///
+    /// let mut sum = 0.;
+    ///
+    /// for value in set.iter().unwrap() {
+    ///     sum += value.unwrap().as_number().unwrap();
+    /// }
+    ///
+    /// assert_eq!(sum, 6.);
+    /// ```
+    pub fn iter(&self) -> Result<JSIterator, JSException> {
+        let context = self.value.ctx;
+        let global_object =
+            unsafe { JSObject::from_raw(context, sys::JSContextGetGlobalObject(context)) };
+        let symbol_iterator = global_object
+            .try_get_property("Symbol")?
+            .as_object()?
+            .try_get_property("iterator")?;
+
+        let method = self.get_property_for_key(&symbol_iterator)?;
+
+        if !method.is_object() || !method.as_object()?.is_function() {
+            return Err(JSValue::new_string_inner(context, "This object is not iterable").into());
+        }
+
+        let iterator = method.as_object()?.call_as_function(Some(self), &[])?;
+
+        if !iterator.is_object() {
+            return Err(JSValue::new_string_inner(context, "Iterator is not an object").into());
+        }
+
+        let iterator = iterator.as_object()?;
+        let next = iterator.try_get_property("next")?.as_object()?;
+
+        Ok(JSIterator::new(iterator, next))
+    }
+
     /// Tests whether an object has a given property.
     ///
     /// * `name`: A value that can be converted to a [`JSString`] containing
@@ -72,6 +400,9 @@ impl JSObject {
     /// Returns the property's value if object has the property, otherwise
     /// the undefined value.
     ///
+    /// An exception thrown while getting the property, e.g. by a getter, is
+    /// ignored. Use [`JSObject::try_get_property()`] to handle it.
+    ///
     /// ```
     /// # use javascriptcore::{JSContext, JSObject, JSString, JSValue};
     /// let ctx = JSContext::default();
@@ -90,6 +421,7 @@ impl JSObject {
     /// * [`JSObject::has_property()`]
     /// * [`JSObject::set_property()`]
     /// * [`JSObject::set_property_at_index()`]
+    /// * [`JSObject::try_get_property()`]
     pub fn get_property<S>(&self, name: S) -> JSValue
     where
         S: Into<JSString>,
@@ -102,6 +434,135 @@ impl JSObject {
         unsafe { JSValue::from_raw(self.value.ctx, value) }
     }
 
+    /// Gets a property from an object, or the exception thrown while getting
+    /// it.
+    ///
+    /// * `name`: A value that can be converted to a [`JSString`] containing
+    ///   the property's name.
+    ///
+    /// Returns the property's value if object has the property, otherwise
+    /// the undefined value. If getting the property throws, e.g. because it
+    /// has a getter that throws, the exception is returned as an error.
+    ///
+    /// ```
+    /// # use javascriptcore::{evaluate_script, JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let o = evaluate_script(
+    ///     &ctx,
+    ///     "({ a: 1, get b() { throw new Error('nope') } })",
+    ///     None,
+    ///     "foo.js",
+    ///     1,
+    /// )
+    /// .unwrap()
+    /// .as_object()
+    /// .unwrap();
+    ///
+    /// assert_eq!(o.try_get_property("a").unwrap().as_number().unwrap(), 1.0);
+    /// assert!(o.try_get_property("b").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::get_property()`]
+    /// * [`JSObject::set_property()`]
+    pub fn try_get_property<S>(&self, name: S) -> Result<JSValue, JSException>
+    where
+        S: Into<JSString>,
+    {
+        let name: JSString = name.into();
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let value =
+            unsafe { sys::JSObjectGetProperty(context, self.raw, name.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(context, value) })
+    }
+
+    /// Gets the string discriminant of a tagged object, e.g. the `type` of
+    /// `{ type: "click", x: 0, y: 0 }`, to tell the variants of a
+    /// discriminated union apart.
+    ///
+    /// * `field`: A value that can be converted to a [`JSString`] containing
+    ///   the name of the discriminant property.
+    ///
+    /// No conversion happens: an [exception](JSException) is returned if the
+    /// property isn't a string, e.g. if it's missing, or if a getter threw.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let event = JSValue::new_from_json(&ctx, r#"{"type": "click", "x": 1}"#)
+    ///     .unwrap()
+    ///     .as_object()
+    ///     .unwrap();
+    ///
+    /// match event.tag("type").unwrap().to_string().as_str() {
+    ///     "click" => assert_eq!(event.get_property("x").as_number().unwrap(), 1.),
+    ///     _ => unreachable!(),
+    /// }
+    ///
+    /// assert!(event.tag("kind").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::try_get_property()`]
+    /// * [`JSValue::extract()`]
+    pub fn tag<S>(&self, field: S) -> Result<JSString, JSException>
+    where
+        S: Into<JSString>,
+    {
+        self.try_get_property(field)?.extract()
+    }
+
+    /// Gets the name of the constructor of this object, i.e.
+    /// `object.constructor.name`, e.g. to log what kind of object it is.
+    ///
+    /// It tells built-in objects like `Date`, `RegExp` or `Array` apart, as
+    /// well as instances of JavaScript classes.
+    ///
+    /// Returns `None` if the object has no constructor, e.g. if it has no
+    /// prototype, if the constructor is anonymous, or if reading either
+    /// property threw. Like in JavaScript, the `constructor` property can be
+    /// overridden, so it identifies objects for logging, not for security.
+    ///
+    /// ```
+    /// # use javascriptcore::{evaluate_script, JSContext};
+    /// let ctx = JSContext::default();
+    /// let date = evaluate_script(&ctx, "new Date()", None, "foo.js", 1)
+    ///     .unwrap()
+    ///     .as_object()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(date.class_name().as_deref(), Some("Date"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::prototype()`]
+    pub fn class_name(&self) -> Option<String> {
+        let constructor = self.try_get_property("constructor").ok()?;
+
+        if !constructor.is_object() {
+            return None;
+        }
+
+        let name = constructor
+            .as_object()
+            .ok()?
+            .try_get_property("name")
+            .ok()?;
+        let name = name.as_js_string()?.to_string();
+
+        (!name.is_empty()).then_some(name)
+    }
+
     /// Gets a property from an object by numeric index.
     ///
     /// * `index`: An integer value that is the property's name.
@@ -149,6 +610,7 @@ impl JSObject {
     /// * [`JSObject::has_property()`]
     /// * [`JSObject::set_property()`]
     /// * [`JSObject::set_property_at_index()`]
+    /// * [`JSObject::try_get_property_at_index()`]
     pub fn get_property_at_index(&self, index: u32) -> JSValue {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let value = unsafe {
@@ -158,6 +620,78 @@ impl JSObject {
         unsafe { JSValue::from_raw(self.value.ctx, value) }
     }
 
+    /// Gets a property from an object by numeric index, or the exception
+    /// thrown while getting it.
+    ///
+    /// * `index`: An integer value that is the property's name.
+    ///
+    /// This is the numeric counterpart of [`JSObject::try_get_property()`].
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let v = JSValue::new_from_json(&ctx, "[3, true]").expect("valid array");
+    /// let o = v.as_object().expect("object");
+    ///
+    /// assert_eq!(o.try_get_property_at_index(0).unwrap().as_number().unwrap(), 3.0);
+    /// assert!(o.try_get_property_at_index(2).unwrap().is_undefined());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::get_property_at_index()`]
+    /// * [`JSObject::set_property_at_index()`]
+    /// * [`JSObject::try_get_property()`]
+    pub fn try_get_property_at_index(&self, index: u32) -> Result<JSValue, JSException> {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let value =
+            unsafe { sys::JSObjectGetPropertyAtIndex(context, self.raw, index, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(context, value) })
+    }
+
+    /// Gets the `length` of an array-like object, like an array, a Typed
+    /// Array, a string object or `arguments`.
+    ///
+    /// Returns an [exception](JSException) if one was thrown by a getter, or
+    /// if the object isn't array-like, i.e. its `length` isn't an integer
+    /// between `0` and `2^32 - 1`, the maximum length of an array.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let array = JSValue::new_from_json(&ctx, "[1, 2, 3]").unwrap().as_object().unwrap();
+    /// let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object().unwrap();
+    ///
+    /// assert_eq!(array.array_length().unwrap(), 3);
+    /// assert!(object.array_length().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::get_property_at_index()`]
+    /// * [`JSValue::is_array()`]
+    pub fn array_length(&self) -> Result<u32, JSException> {
+        let length = self.try_get_property("length")?;
+
+        match length.as_f64() {
+            Some(length)
+                if length >= 0. && length <= f64::from(u32::MAX) && length.fract() == 0. =>
+            {
+                Ok(length as u32)
+            }
+            _ => Err(
+                JSValue::new_string_inner(self.value.ctx, "This object is not array-like").into(),
+            ),
+        }
+    }
+
     /// Set a property onto an object.
     ///
     /// This can be used to create a new property, or to update an existing property.
@@ -213,34 +747,109 @@ impl JSObject {
         Ok(())
     }
 
-    /// Set a property onto an object by using a numeric index.
+    /// Set many properties onto an object, e.g. to install the globals of a
+    /// host environment.
     ///
-    /// This can be used to create a new property, or to update an existing property.
-    ///
-    /// * `index`: An integer value that is the property's name.
-    /// * `value`: A value containing the property's value.
+    /// * `properties`: The names and values of the properties, e.g. as a
+    ///   `HashMap`, a `Vec` or an array of pairs.
     ///
-    /// Calling `set_property_at_index` is equivalent to calling `set_property` with
-    /// a string containing `index`, but `set_property_at_index` provides optimized
-    /// access to numeric properties.
+    /// The properties are set in order, as with [`JSObject::set_property()`],
+    /// stopping at the first [exception](JSException) thrown.
     ///
     /// ```
     /// # use javascriptcore::{JSContext, JSValue};
     /// let ctx = JSContext::default();
-    /// let object = JSValue::new_from_json(&ctx, r#"[10]"#).expect("valid array").as_object().unwrap();
-    /// object.set_property_at_index(1, JSValue::new_number(&ctx, 11.)).unwrap();
+    /// let global_object = ctx.global_object().unwrap();
     ///
-    /// assert!(object.has_property("0"));
-    /// assert!(object.has_property("1"));
+    /// global_object
+    ///     .set_properties([
+    ///         ("VERSION", JSValue::new_string(&ctx, "1.0")),
+    ///         ("DEBUG", JSValue::new_boolean(&ctx, false)),
+    ///     ])
+    ///     .unwrap();
+    ///
+    /// assert!(global_object.has_property("VERSION"));
+    /// assert!(global_object.has_property("DEBUG"));
     /// ```
     ///
     /// # See also
     ///
-    /// * [`JSObject::get_property()`]
-    /// * [`JSObject::get_property_at_index()`]
-    /// * [`JSObject::has_property()`]
     /// * [`JSObject::set_property()`]
-    pub fn set_property_at_index(&self, index: u32, value: JSValue) -> Result<(), JSException> {
+    pub fn set_properties<I, S>(&self, properties: I) -> Result<(), JSException>
+    where
+        I: IntoIterator<Item = (S, JSValue)>,
+        S: Into<JSString>,
+    {
+        properties
+            .into_iter()
+            .try_for_each(|(name, value)| self.set_property(name, value))
+    }
+
+    /// Copies the own enumerable properties of `sources` onto this object,
+    /// like `Object.assign()` in JavaScript, e.g. to merge configuration
+    /// objects.
+    ///
+    /// * `sources`: The objects to copy the properties of, in order: when
+    ///   several sources have the same property, the last one wins.
+    ///
+    /// As with `Object.assign()`, the getters of the sources and the setters
+    /// of this object are called. Returns an [exception](JSException) if one
+    /// of them threw; properties copied before it are kept.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let config = JSValue::new_from_json(&ctx, r#"{"debug": false, "port": 80}"#).unwrap().as_object().unwrap();
+    /// let overrides = JSValue::new_from_json(&ctx, r#"{"debug": true}"#).unwrap().as_object().unwrap();
+    ///
+    /// config.assign(&[&overrides]).unwrap();
+    ///
+    /// assert!(config.get_property("debug").as_boolean());
+    /// assert_eq!(config.get_property("port").as_number().unwrap(), 80.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::set_properties()`]
+    pub fn assign(&self, sources: &[&JSObject]) -> Result<(), JSException> {
+        let arguments = std::iter::once(self)
+            .chain(sources.iter().copied())
+            .map(JSValue::from)
+            .collect::<Vec<_>>();
+
+        self.call_object_function("assign", &arguments)?;
+
+        Ok(())
+    }
+
+    /// Set a property onto an object by using a numeric index.
+    ///
+    /// This can be used to create a new property, or to update an existing property.
+    ///
+    /// * `index`: An integer value that is the property's name.
+    /// * `value`: A value containing the property's value.
+    ///
+    /// Calling `set_property_at_index` is equivalent to calling `set_property` with
+    /// a string containing `index`, but `set_property_at_index` provides optimized
+    /// access to numeric properties.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let object = JSValue::new_from_json(&ctx, r#"[10]"#).expect("valid array").as_object().unwrap();
+    /// object.set_property_at_index(1, JSValue::new_number(&ctx, 11.)).unwrap();
+    ///
+    /// assert!(object.has_property("0"));
+    /// assert!(object.has_property("1"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::get_property()`]
+    /// * [`JSObject::get_property_at_index()`]
+    /// * [`JSObject::has_property()`]
+    /// * [`JSObject::set_property()`]
+    pub fn set_property_at_index(&self, index: u32, value: JSValue) -> Result<(), JSException> {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let context = self.value.ctx;
 
@@ -255,141 +864,694 @@ impl JSObject {
         Ok(())
     }
 
-    /// Returns `true` if the object can be called as a constructor, otherwise `false`.
+    /// Gets a property from an object, using a value as the property key,
+    /// like `object[key]` in JavaScript.
     ///
-    /// ```rust
+    /// * `key`: The property key. Contrary to [`JSObject::try_get_property()`],
+    ///   it can be a symbol, e.g. a well-known symbol like
+    ///   `Symbol.toStringTag`. Other values are converted to strings.
+    ///
+    /// Returns either the property's value, which is undefined if the object
+    /// doesn't have the property, or an [exception](JSException) if one was
+    /// thrown, e.g. by a getter.
+    ///
+    /// ```
     /// # use javascriptcore::{JSContext, JSValue};
     /// let ctx = JSContext::default();
-    /// let global = ctx.global_object().unwrap();
-    ///
-    /// let number = global.get_property("Number").as_object().unwrap();
-    /// assert!(number.is_constructor());
+    /// let object = ctx.evaluate_script("new Map()", None, 1).unwrap().as_object().unwrap();
+    /// let to_string_tag = ctx
+    ///     .evaluate_script("Symbol.toStringTag", None, 1)
+    ///     .unwrap();
     ///
-    /// let math = global.get_property("Math").as_object().unwrap();
-    /// let pow = math.get_property("pow").as_object().unwrap();
-    /// assert!(!pow.is_constructor());
+    /// let tag = object.get_property_for_key(&to_string_tag).unwrap();
+    /// assert_eq!(tag.as_string().unwrap(), "Map");
     /// ```
     ///
     /// # See also
     ///
-    /// - [`JSObject::call_as_constructor()`]
-    pub fn is_constructor(&self) -> bool {
-        unsafe { sys::JSObjectIsConstructor(self.value.ctx, self.raw) }
+    /// * [`JSObject::set_property_for_key()`]
+    /// * [`JSObject::try_get_property()`]
+    pub fn get_property_for_key(&self, key: &JSValue) -> Result<JSValue, JSException> {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let value =
+            unsafe { sys::JSObjectGetPropertyForKey(context, self.raw, key.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(context, value) })
     }
 
-    /// Call this object considering it is a valid object constructor.
+    /// Sets a property on an object, using a value as the property key,
+    /// like `object[key] = value` in JavaScript.
     ///
-    /// ```rust
+    /// * `key`: The property key. Contrary to [`JSObject::set_property()`],
+    ///   it can be a symbol. Other values are converted to strings.
+    /// * `value`: A value containing the property's value.
+    ///
+    /// ```
     /// # use javascriptcore::{JSContext, JSValue};
     /// let ctx = JSContext::default();
-    /// let global = ctx.global_object().unwrap();
-    /// let number = global.get_property("Number").as_object().unwrap();
+    /// let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object().unwrap();
+    /// let key = JSValue::new_symbol(&ctx, "secret");
     ///
-    /// let result = number.call_as_constructor(&[JSValue::new_string(&ctx, "42")]).unwrap();
+    /// object.set_property_for_key(&key, JSValue::new_number(&ctx, 42.)).unwrap();
     ///
-    /// assert!(result.is_object());
-    /// assert_eq!(result.as_number().unwrap(), 42.);
+    /// assert_eq!(object.get_property_for_key(&key).unwrap().as_number().unwrap(), 42.);
+    /// assert!(!object.has_property("secret"));
     /// ```
     ///
     /// # See also
     ///
-    /// - [`JSObject::call_as_function()`]
-    /// - [`JSObject::is_constructor()`]
-    pub fn call_as_constructor(&self, arguments: &[JSValue]) -> Result<JSValue, JSException> {
-        let arguments = arguments
-            .iter()
-            .map(|argument| argument.raw)
-            .collect::<Vec<_>>();
+    /// * [`JSObject::get_property_for_key()`]
+    /// * [`JSObject::set_property()`]
+    pub fn set_property_for_key(&self, key: &JSValue, value: JSValue) -> Result<(), JSException> {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let context = self.value.ctx;
 
-        let result = unsafe {
-            sys::JSObjectCallAsConstructor(
+        unsafe {
+            sys::JSObjectSetPropertyForKey(
                 context,
                 self.raw,
-                arguments.len(),
-                arguments.as_slice().as_ptr(),
+                key.raw,
+                value.raw,
+                sys::kJSPropertyAttributeNone,
                 &mut exception,
-            )
-        };
+            );
+        }
 
         if !exception.is_null() {
             return Err(unsafe { JSValue::from_raw(context, exception) }.into());
         }
 
-        if result.is_null() {
-            return Err(JSValue::new_string_inner(
+        Ok(())
+    }
+
+    /// Deletes a property from an object.
+    ///
+    /// * `name`: A value that can be converted to a [`JSString`] containing
+    ///   the property's name.
+    ///
+    /// Returns either `true` if the delete operation succeeds, `false` if
+    /// the property can't be deleted (e.g. it was set with the
+    /// `DontDelete` attribute, or is non-configurable), or an
+    /// [exception](JSException) if one was thrown (e.g. by a `Proxy`).
+    /// Deleting a property which doesn't exist succeeds.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let object = JSValue::new_from_json(&ctx, r#"{"a": 10}"#).expect("valid object").as_object().unwrap();
+    ///
+    /// assert!(object.delete_property("a").unwrap());
+    /// assert!(!object.has_property("a"));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::has_property()`]
+    /// * [`JSObject::set_property()`]
+    pub fn delete_property<S>(&self, name: S) -> Result<bool, JSException>
+    where
+        S: Into<JSString>,
+    {
+        let name: JSString = name.into();
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let deleted =
+            unsafe { sys::JSObjectDeleteProperty(context, self.raw, name.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        Ok(deleted)
+    }
+
+    /// Defines a property on an object, with the given descriptor.
+    ///
+    /// * `name`: A value that can be converted to a [`JSString`] containing
+    ///   the property's name.
+    /// * `descriptor`: The value or accessors of the property, and its
+    ///   attributes.
+    ///
+    /// A value property which doesn't exist yet is set directly with its
+    /// attributes. Otherwise, i.e. to redefine a property or to define an
+    /// accessor property, `Object.defineProperty` is called, as the C API
+    /// can't do it.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. when
+    /// redefining a property which isn't configurable.
+    ///
+    /// ```
+    /// # use javascriptcore::{JSContext, JSPropertyDescriptor, JSValue};
+    /// let ctx = JSContext::default();
+    /// let object = JSValue::new_from_json(&ctx, "{}").expect("valid object").as_object().unwrap();
+    ///
+    /// object
+    ///     .define_property(
+    ///         "answer",
+    ///         JSPropertyDescriptor::value(JSValue::new_number(&ctx, 42.)).read_only(),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// object.set_property("answer", JSValue::new_number(&ctx, 43.)).unwrap();
+    /// assert_eq!(object.get_property("answer").as_number().unwrap(), 42.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::delete_property()`]
+    /// * [`JSObject::set_property()`]
+    pub fn define_property<S>(
+        &self,
+        name: S,
+        descriptor: JSPropertyDescriptor,
+    ) -> Result<(), JSException>
+    where
+        S: Into<JSString>,
+    {
+        let name: JSString = name.into();
+        let context = self.value.ctx;
+
+        let has_property = unsafe { sys::JSObjectHasProperty(context, self.raw, name.raw) };
+
+        if let (JSPropertyDescriptorKind::Value(value), false) = (&descriptor.kind, has_property) {
+            let mut exception: sys::JSValueRef = ptr::null_mut();
+
+            unsafe {
+                sys::JSObjectSetProperty(
+                    context,
+                    self.raw,
+                    name.raw,
+                    value.raw,
+                    descriptor.attributes,
+                    &mut exception,
+                );
+            }
+
+            if !exception.is_null() {
+                return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+            }
+
+            return Ok(());
+        }
+
+        let boolean = |boolean| unsafe {
+            JSValue::from_raw(context, sys::JSValueMakeBoolean(context, boolean))
+        };
+        let has_attribute = |attribute| descriptor.attributes & attribute != 0;
+
+        let attributes = unsafe {
+            JSObject::from_raw(
                 context,
-                "Cannot call this object as a constructor: it is not a valid constructor",
+                sys::JSObjectMake(context, ptr::null_mut(), ptr::null_mut()),
             )
-            .into());
+        };
+        attributes.set_property(
+            "enumerable",
+            boolean(!has_attribute(sys::kJSPropertyAttributeDontEnum)),
+        )?;
+        attributes.set_property(
+            "configurable",
+            boolean(!has_attribute(sys::kJSPropertyAttributeDontDelete)),
+        )?;
+
+        match descriptor.kind {
+            JSPropertyDescriptorKind::Value(value) => {
+                attributes.set_property("value", value)?;
+                attributes.set_property(
+                    "writable",
+                    boolean(!has_attribute(sys::kJSPropertyAttributeReadOnly)),
+                )?;
+            }
+            JSPropertyDescriptorKind::Accessor { getter, setter } => {
+                if let Some(getter) = getter {
+                    attributes.set_property("get", getter.into())?;
+                }
+
+                if let Some(setter) = setter {
+                    attributes.set_property("set", setter.into())?;
+                }
+            }
         }
 
-        Ok(unsafe { JSValue::from_raw(context, result) })
+        self.call_object_function(
+            "defineProperty",
+            &[
+                self.into(),
+                JSValue::new_string_inner(context, name),
+                attributes.into(),
+            ],
+        )?;
+
+        Ok(())
     }
 
-    /// Returns `true` if the object can be called as a constructor, otherwise `false`.
+    /// Freezes an object, like `Object.freeze()` in JavaScript.
     ///
-    /// ```rust
+    /// Its properties can't be added, removed nor changed anymore, and its
+    /// prototype can't be replaced: such writes are silently ignored, or
+    /// throw in strict mode. Freezing is shallow, the objects held by the
+    /// properties aren't frozen.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. by a
+    /// `Proxy`.
+    ///
+    /// ```
     /// # use javascriptcore::{JSContext, JSValue};
     /// let ctx = JSContext::default();
-    /// let global = ctx.global_object().unwrap();
-    ///
-    /// let number = global.get_property("Number").as_object().unwrap();
-    /// assert!(number.is_function());
+    /// let object = JSValue::new_from_json(&ctx, r#"{"a": 10}"#).expect("valid object").as_object().unwrap();
     ///
-    /// let math = global.get_property("Math").as_object().unwrap();
-    /// let pow = math.get_property("pow").as_object().unwrap();
-    /// assert!(pow.is_function());
+    /// object.freeze().unwrap();
+    /// object.set_property("a", JSValue::new_number(&ctx, 11.)).unwrap();
     ///
-    /// let pi = math.get_property("PI").as_object().unwrap();
-    /// assert!(!pi.is_function());
+    /// assert!(object.is_frozen().unwrap());
+    /// assert_eq!(object.get_property("a").as_number().unwrap(), 10.);
     /// ```
     ///
     /// # See also
     ///
-    /// - [`JSObject::call_as_function()`]
-    pub fn is_function(&self) -> bool {
-        unsafe { sys::JSObjectIsFunction(self.value.ctx, self.raw) }
+    /// * [`JSObject::is_frozen()`]
+    /// * [`JSObject::seal()`]
+    pub fn freeze(&self) -> Result<(), JSException> {
+        self.call_object_function("freeze", &[self.into()])?;
+
+        Ok(())
     }
 
-    /// Call this object considering it is a valid function.
+    /// Seals an object, like `Object.seal()` in JavaScript.
     ///
-    /// ```rust
+    /// Its properties can't be added nor removed anymore, but the values of
+    /// the writable ones can still be changed.
+    ///
+    /// Returns an [exception](JSException) if one was thrown, e.g. by a
+    /// `Proxy`.
+    ///
+    /// ```
     /// # use javascriptcore::{JSContext, JSValue};
     /// let ctx = JSContext::default();
-    /// let global = ctx.global_object().unwrap();
-    /// let math = global.get_property("Math").as_object().unwrap();
-    /// let pow = math.get_property("pow").as_object().unwrap();
+    /// let object = JSValue::new_from_json(&ctx, r#"{"a": 10}"#).expect("valid object").as_object().unwrap();
     ///
-    /// let result = pow.call_as_function(
-    ///     None,
-    ///     &[JSValue::new_number(&ctx, 2.), JSValue::new_number(&ctx, 3.)],
-    /// ).unwrap();
+    /// object.seal().unwrap();
+    /// object.set_property("a", JSValue::new_number(&ctx, 11.)).unwrap();
+    /// object.set_property("b", JSValue::new_number(&ctx, 12.)).unwrap();
     ///
-    /// assert_eq!(result.as_number().unwrap(), 8.);
+    /// assert!(object.is_sealed().unwrap());
+    /// assert_eq!(object.get_property("a").as_number().unwrap(), 11.);
+    /// assert!(!object.has_property("b"));
     /// ```
     ///
     /// # See also
     ///
-    /// - [`JSObject::call_as_constructor()`]
-    /// - [`JSObject::is_function()`]
-    pub fn call_as_function(
+    /// * [`JSObject::freeze()`]
+    /// * [`JSObject::is_sealed()`]
+    pub fn seal(&self) -> Result<(), JSException> {
+        self.call_object_function("seal", &[self.into()])?;
+
+        Ok(())
+    }
+
+    /// Tests whether an object is frozen, like `Object.isFrozen()` in
+    /// JavaScript.
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::freeze()`]
+    pub fn is_frozen(&self) -> Result<bool, JSException> {
+        Ok(self
+            .call_object_function("isFrozen", &[self.into()])?
+            .as_boolean())
+    }
+
+    /// Tests whether an object is sealed, like `Object.isSealed()` in
+    /// JavaScript. Frozen objects are sealed too.
+    ///
+    /// # See also
+    ///
+    /// * [`JSObject::seal()`]
+    pub fn is_sealed(&self) -> Result<bool, JSException> {
+        Ok(self
+            .call_object_function("isSealed", &[self.into()])?
+            .as_boolean())
+    }
+
+    /// Calls a function of the global `Object` constructor, e.g.
+    /// `Object.freeze()`, for what the C API can't do.
+    fn call_object_function(
         &self,
-        this: Option<&JSObject>,
+        name: &str,
         arguments: &[JSValue],
     ) -> Result<JSValue, JSException> {
-        let arguments = arguments
-            .iter()
-            .map(|argument| argument.raw)
-            .collect::<Vec<_>>();
+        let context = self.value.ctx;
+        let global_object =
+            unsafe { JSObject::from_raw(context, sys::JSContextGetGlobalObject(context)) };
+        let object = global_object.try_get_property("Object")?.as_object()?;
+        let function = object.try_get_property(name)?.as_object()?;
+
+        function.call_as_function(Some(&object), arguments)
+    }
+
+    /// Returns the bytes of this object, considering it is an `ArrayBuffer`.
+    ///
+    /// Returns an error if the object isn't an `ArrayBuffer`, see
+    /// [`JSValue::is_array_buffer()`].
+    ///
+    /// # Safety
+    ///
+    /// The pointer of the slice returned by this function is temporary and is not
+    /// guaranteed to remain valid across JavaScriptCore API calls. JavaScript can
+    /// also mutate the bytes, or detach the buffer, while the slice is alive.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let buffer = JSValue::new_array_buffer(&ctx, vec![1u8, 2, 3])
+    ///     .unwrap()
+    ///     .as_object()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(unsafe { buffer.array_buffer_bytes() }.unwrap(), &[1, 2, 3]);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_array_buffer()`]
+    pub unsafe fn array_buffer_bytes(&self) -> Result<&[u8], JSException> {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let context = self.value.ctx;
 
-        let result = unsafe {
-            sys::JSObjectCallAsFunction(
-                context,
-                self.raw,
+        let pointer =
+            unsafe { sys::JSObjectGetArrayBufferBytesPtr(context, self.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        if pointer.is_null() {
+            return Err(JSValue::new_string_inner(
+                context,
+                "Cannot get the bytes of this object: it is not an array buffer",
+            )
+            .into());
+        }
+
+        let length =
+            unsafe { sys::JSObjectGetArrayBufferByteLength(context, self.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        Ok(unsafe { slice::from_raw_parts(pointer.cast::<u8>(), length) })
+    }
+
+    /// Returns the number of milliseconds since the Unix epoch of this object,
+    /// considering it is a `Date`.
+    ///
+    /// Returns `None` if the object isn't a `Date`, and `Some(f64::NAN)` for
+    /// an `Invalid Date`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{evaluate_script, JSContext};
+    /// let ctx = JSContext::default();
+    /// let date = evaluate_script(&ctx, "new Date(Date.UTC(1970, 0, 2))", None, "foo.js", 1)
+    ///     .unwrap()
+    ///     .as_object()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(date.date_milliseconds(), Some(86_400_000.));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSContext::new_date()`](crate::JSContext::new_date)
+    /// - [`JSValue::is_date()`]
+    pub fn date_milliseconds(&self) -> Option<f64> {
+        if !self.is_date() {
+            return None;
+        }
+
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let milliseconds =
+            unsafe { sys::JSValueToNumber(self.value.ctx, self.value.raw, &mut exception) };
+
+        if exception.is_null() {
+            Some(milliseconds)
+        } else {
+            None
+        }
+    }
+
+    /// Calls the `test` method of this object, considering it is a `RegExp`.
+    ///
+    /// * `input`: The string to search a match in.
+    ///
+    /// Returns whether `input` matches the regular expression, otherwise an
+    /// [exception](JSException), e.g. if the object isn't a `RegExp`.
+    ///
+    /// Note that for global or sticky regular expressions, this updates the
+    /// `lastIndex` of the `RegExp`, exactly like in JavaScript.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let regexp = ctx.new_regexp("o+", "").unwrap();
+    ///
+    /// assert!(regexp.regexp_test("foo").unwrap());
+    /// assert!(!regexp.regexp_test("bar").unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSContext::new_regexp()`](crate::JSContext::new_regexp)
+    /// - [`JSObject::regexp_exec()`]
+    pub fn regexp_test<S>(&self, input: S) -> Result<bool, JSException>
+    where
+        S: Into<JSString>,
+    {
+        Ok(self.call_regexp_method("test", input)?.as_boolean())
+    }
+
+    /// Calls the `exec` method of this object, considering it is a `RegExp`.
+    ///
+    /// * `input`: The string to search a match in.
+    ///
+    /// Returns the match, i.e. an array with the matched string followed by
+    /// the captured groups, and with `index` and `groups` properties. Returns
+    /// `None` if there is no match, or an [exception](JSException), e.g. if
+    /// the object isn't a `RegExp`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSContext;
+    /// let ctx = JSContext::new();
+    /// let regexp = ctx.new_regexp("(?<word>o+)", "").unwrap();
+    ///
+    /// let matches = regexp.regexp_exec("foo").unwrap().unwrap();
+    /// assert_eq!(matches.get_property_at_index(1).as_string().unwrap(), "oo");
+    /// assert_eq!(matches.get_property("index").as_number().unwrap(), 1.);
+    ///
+    /// assert!(regexp.regexp_exec("bar").unwrap().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSContext::new_regexp()`](crate::JSContext::new_regexp)
+    /// - [`JSObject::regexp_test()`]
+    pub fn regexp_exec<S>(&self, input: S) -> Result<Option<JSObject>, JSException>
+    where
+        S: Into<JSString>,
+    {
+        let result = self.call_regexp_method("exec", input)?;
+
+        if result.is_null() {
+            Ok(None)
+        } else {
+            result.as_object().map(Some)
+        }
+    }
+
+    fn call_regexp_method<S>(&self, method: &str, input: S) -> Result<JSValue, JSException>
+    where
+        S: Into<JSString>,
+    {
+        let input = unsafe {
+            JSValue::from_raw(
+                self.value.ctx,
+                sys::JSValueMakeString(self.value.ctx, input.into().raw),
+            )
+        };
+        let method = self.try_get_property(method)?;
+
+        if !method.is_object() {
+            return Err(JSValue::new_string_inner(
+                self.value.ctx,
+                "Cannot use this object as a regular expression: it is not a valid `RegExp`",
+            )
+            .into());
+        }
+
+        method.as_object()?.call_as_function(Some(self), &[input])
+    }
+
+    /// Returns `true` if the object can be called as a constructor, otherwise `false`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let global = ctx.global_object().unwrap();
+    ///
+    /// let number = global.get_property("Number").as_object().unwrap();
+    /// assert!(number.is_constructor());
+    ///
+    /// let math = global.get_property("Math").as_object().unwrap();
+    /// let pow = math.get_property("pow").as_object().unwrap();
+    /// assert!(!pow.is_constructor());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::call_as_constructor()`]
+    pub fn is_constructor(&self) -> bool {
+        unsafe { sys::JSObjectIsConstructor(self.value.ctx, self.raw) }
+    }
+
+    /// Call this object considering it is a valid object constructor.
+    ///
+    /// This is the equivalent of the `new` operator in JavaScript.
+    ///
+    /// * `arguments`: The arguments to pass to the constructor.
+    ///
+    /// Returns the constructed object, or the exception thrown by the
+    /// constructor. If the object isn't a constructor (see
+    /// [`JSObject::is_constructor()`]), an error is returned too, with a
+    /// message explaining it.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let global = ctx.global_object().unwrap();
+    /// let number = global.get_property("Number").as_object().unwrap();
+    ///
+    /// let result = number.call_as_constructor(&[JSValue::new_string(&ctx, "42")]).unwrap();
+    ///
+    /// assert!(result.is_object());
+    /// assert_eq!(result.as_number().unwrap(), 42.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::call_as_function()`]
+    /// - [`JSObject::is_constructor()`]
+    pub fn call_as_constructor(&self, arguments: &[JSValue]) -> Result<JSValue, JSException> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| argument.raw)
+            .collect::<Vec<_>>();
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let result = unsafe {
+            sys::JSObjectCallAsConstructor(
+                context,
+                self.raw,
+                arguments.len(),
+                arguments.as_slice().as_ptr(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception) }.into());
+        }
+
+        if result.is_null() {
+            return Err(JSValue::new_string_inner(
+                context,
+                "Cannot call this object as a constructor: it is not a valid constructor",
+            )
+            .into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(context, result) })
+    }
+
+    /// Returns `true` if the object can be called as a function, otherwise `false`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let global = ctx.global_object().unwrap();
+    ///
+    /// let number = global.get_property("Number").as_object().unwrap();
+    /// assert!(number.is_function());
+    ///
+    /// let math = global.get_property("Math").as_object().unwrap();
+    /// let pow = math.get_property("pow").as_object().unwrap();
+    /// assert!(pow.is_function());
+    ///
+    /// let pi = math.get_property("PI").as_object().unwrap();
+    /// assert!(!pi.is_function());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::call_as_function()`]
+    pub fn is_function(&self) -> bool {
+        unsafe { sys::JSObjectIsFunction(self.value.ctx, self.raw) }
+    }
+
+    /// Call this object considering it is a valid function.
+    ///
+    /// * `this`: The object to use as `this`. With `None`, the global object
+    ///   is used, like for a plain function call in non-strict mode.
+    /// * `arguments`: The arguments to pass to the function.
+    ///
+    /// Returns the function's return value, or the exception thrown by the
+    /// function. If the object isn't a function (see
+    /// [`JSObject::is_function()`]), an error is returned too.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let global = ctx.global_object().unwrap();
+    /// let math = global.get_property("Math").as_object().unwrap();
+    /// let pow = math.get_property("pow").as_object().unwrap();
+    ///
+    /// let result = pow.call_as_function(
+    ///     None,
+    ///     &[JSValue::new_number(&ctx, 2.), JSValue::new_number(&ctx, 3.)],
+    /// ).unwrap();
+    ///
+    /// assert_eq!(result.as_number().unwrap(), 8.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::call_as_constructor()`]
+    /// - [`JSObject::is_function()`]
+    pub fn call_as_function(
+        &self,
+        this: Option<&JSObject>,
+        arguments: &[JSValue],
+    ) -> Result<JSValue, JSException> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| argument.raw)
+            .collect::<Vec<_>>();
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let context = self.value.ctx;
+
+        let result = unsafe {
+            sys::JSObjectCallAsFunction(
+                context,
+                self.raw,
                 this.map_or_else(ptr::null_mut, |this| this.raw),
                 arguments.len(),
                 arguments.as_slice().as_ptr(),
@@ -397,139 +1559,832 @@ impl JSObject {
             )
         };
 
-        if !exception.is_null() {
-            return Err(unsafe { JSValue::from_raw(context, exception).into() });
-        }
+        if !exception.is_null() {
+            return Err(unsafe { JSValue::from_raw(context, exception).into() });
+        }
+
+        if result.is_null() {
+            return Err(JSValue::new_string_inner(
+                context,
+                "Cannot call this object as a function: it is not a valid function",
+            )
+            .into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(context, result) })
+    }
+}
+
+/// A `JSObject` can be dereferenced to return the underlying `JSValue`.
+///
+/// This lets a `JSObject` instance be used where a `JSValue` instance is
+/// expected.
+impl Deref for JSObject {
+    type Target = JSValue;
+
+    fn deref(&self) -> &JSValue {
+        &self.value
+    }
+}
+
+impl From<&JSObject> for JSValue {
+    fn from(object: &JSObject) -> Self {
+        // SAFETY: `ctx` and `raw` is valid, it's safe to use them.
+        unsafe { JSValue::from_raw(object.value.ctx, object.value.raw) }
+    }
+}
+
+impl From<JSObject> for JSValue {
+    fn from(object: JSObject) -> Self {
+        (&object).into()
+    }
+}
+
+/// A description of a property, to define it with
+/// [`JSObject::define_property()`].
+///
+/// A property either holds a value, or is computed by accessor
+/// functions. By default, the property is writable, enumerable and
+/// deletable; use [`JSPropertyDescriptor::read_only()`],
+/// [`JSPropertyDescriptor::dont_enum()`] and
+/// [`JSPropertyDescriptor::dont_delete()`] to change that.
+pub struct JSPropertyDescriptor {
+    kind: JSPropertyDescriptorKind,
+    attributes: sys::JSPropertyAttributes,
+}
+
+/// What a [`JSPropertyDescriptor`] describes.
+enum JSPropertyDescriptorKind {
+    Value(JSValue),
+    Accessor {
+        getter: Option<JSObject>,
+        setter: Option<JSObject>,
+    },
+}
+
+impl JSPropertyDescriptor {
+    /// Describes a property holding `value`.
+    pub fn value(value: JSValue) -> Self {
+        Self {
+            kind: JSPropertyDescriptorKind::Value(value),
+            attributes: sys::kJSPropertyAttributeNone,
+        }
+    }
+
+    /// Describes a property computed by accessor functions.
+    ///
+    /// * `getter`: The function called to get the property's value, if any.
+    ///   Without it, the property's value is `undefined`.
+    /// * `setter`: The function called with the new value to set the
+    ///   property, if any. Without it, the property is read-only.
+    ///
+    /// Functions can be created with [`JSContext::make_function()`].
+    ///
+    /// [`JSContext::make_function()`]: crate::JSContext::make_function
+    pub fn accessor(getter: Option<JSObject>, setter: Option<JSObject>) -> Self {
+        Self {
+            kind: JSPropertyDescriptorKind::Accessor { getter, setter },
+            attributes: sys::kJSPropertyAttributeNone,
+        }
+    }
+
+    /// Makes the property read-only, i.e. its value can't be changed.
+    ///
+    /// Setting the property fails silently, or throws in strict mode. It has
+    /// no effect on accessor properties, which are read-only when they have no
+    /// setter.
+    pub fn read_only(mut self) -> Self {
+        self.attributes |= sys::kJSPropertyAttributeReadOnly;
+
+        self
+    }
+
+    /// Makes the property non-enumerable, i.e. hidden from `for...in` loops
+    /// and [`JSObject::property_names()`].
+    pub fn dont_enum(mut self) -> Self {
+        self.attributes |= sys::kJSPropertyAttributeDontEnum;
+
+        self
+    }
+
+    /// Makes the property non-configurable, i.e. it can't be deleted nor
+    /// redefined.
+    pub fn dont_delete(mut self) -> Self {
+        self.attributes |= sys::kJSPropertyAttributeDontDelete;
+
+        self
+    }
+}
+
+/// An iterator over the names of an object's enumerable properties.
+///
+/// It is created by [`JSObject::property_names()`].
+pub struct JSObjectPropertyNameIter {
+    raw: sys::JSPropertyNameArrayRef,
+    idx: usize,
+}
+
+impl Iterator for JSObjectPropertyNameIter {
+    type Item = JSString;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx < unsafe { sys::JSPropertyNameArrayGetCount(self.raw) } {
+            let name = unsafe { sys::JSPropertyNameArrayGetNameAtIndex(self.raw, self.idx) };
+            self.idx += 1;
+            // GetNameAtIndex doesn't retain the name, so since we're going to release it
+            // when we release the property name array, but this JSString may outlive that,
+            // we need to retain the string to keep it alive.
+            Some(JSString {
+                raw: unsafe { sys::JSStringRetain(name) },
+            })
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let sz = unsafe { sys::JSPropertyNameArrayGetCount(self.raw) } - self.idx;
+        (sz, Some(sz))
+    }
+}
+
+impl ExactSizeIterator for JSObjectPropertyNameIter {}
+
+impl Drop for JSObjectPropertyNameIter {
+    fn drop(&mut self) {
+        unsafe { sys::JSPropertyNameArrayRelease(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JSObject;
+    use crate::{
+        evaluate_script, garbage_collect, sys, JSClass, JSContext, JSException,
+        JSPropertyDescriptor, JSValue,
+    };
+    use std::cell::Cell;
+    use std::collections::HashMap;
+    use std::rc::Rc;
+
+    #[test]
+    fn can_has_property() {
+        let ctx = JSContext::default();
+        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
+        let o = v.as_object().expect("object");
+        assert!(o.has_property("id"));
+        assert!(!o.has_property("no-such-value"));
+    }
+
+    #[test]
+    fn can_get_property() {
+        let ctx = JSContext::default();
+        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
+        let o = v.as_object().expect("object");
+        assert!(o.get_property("id").is_number());
+        assert!(o.get_property("no-such-value").is_undefined());
+    }
+
+    #[test]
+    fn can_try_get_property() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = evaluate_script(
+            &ctx,
+            "({ get fails() { throw new TypeError('nope') } })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        object.set_property("foo", JSValue::new_number(&ctx, 42.))?;
+
+        assert_eq!(object.try_get_property("foo")?.as_number()?, 42.);
+        assert!(object.try_get_property("no-such-value")?.is_undefined());
+
+        let exception = object.try_get_property("fails").unwrap_err();
+        assert_eq!(exception.name()?, "TypeError");
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_property_at_index() {
+        let ctx = JSContext::default();
+        let v = JSValue::new_from_json(&ctx, "[3, true, \"abc\"]").expect("value");
+        let o = v.as_object().expect("object");
+        assert!(o.get_property_at_index(0).is_number());
+        assert!(o.get_property_at_index(1).is_boolean());
+        assert!(o.get_property_at_index(2).is_string());
+        assert!(o.get_property_at_index(5).is_undefined());
+    }
+
+    #[test]
+    fn can_get_property_names() {
+        let ctx = JSContext::default();
+        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
+        let o = v.as_object().expect("object");
+        let names = o.property_names().collect::<Vec<_>>();
+        assert_eq!(names.len(), 1);
+        assert_eq!(names[0], "id");
+    }
+
+    #[test]
+    fn can_get_many_property_names() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let o = evaluate_script(
+            &ctx,
+            "const o = { b: 1, a: 2 }; for (let i = 0; i < 1000; i++) { o['k' + i] = i; } o",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        let mut names = o.property_names();
+        assert_eq!(names.len(), 1002);
+
+        assert_eq!(names.next().unwrap(), "b");
+        assert_eq!(names.next().unwrap(), "a");
+        assert_eq!(names.len(), 1000);
+
+        let names = names.map(|name| name.to_string()).collect::<Vec<_>>();
+        let expected = (0..1000).map(|i| format!("k{i}")).collect::<Vec<_>>();
+        assert_eq!(names, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_keys_and_values() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = evaluate_script(
+            &ctx,
+            "Object.assign(Object.create({ inherited: 0 }), { a: 1, b: 'two', [Symbol('c')]: 3 })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+        object.define_property(
+            "hidden",
+            JSPropertyDescriptor::value(JSValue::new_number(&ctx, 4.)).dont_enum(),
+        )?;
+
+        assert_eq!(object.keys()?, ["a", "b"]);
+
+        let values = object.values()?;
+        assert_eq!(values.len(), 2);
+        assert_eq!(values[0].as_number()?, 1.);
+        assert_eq!(values[1].as_string()?, "two");
+
+        // Inherited properties are enumerated by `property_names`, though.
+        let names = object.property_names().collect::<Vec<_>>();
+        assert_eq!(names, ["a", "b", "inherited"]);
+
+        let empty = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+        assert!(empty.keys()?.is_empty());
+        assert!(empty.values()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_own_property_names() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let config = evaluate_script(
+            &ctx,
+            "Object.defineProperty(Object.create({ inherited: 0 }), 'secret', { value: 1, enumerable: false })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+        config.set_property("public", JSValue::new_number(&ctx, 2.))?;
+
+        assert_eq!(config.keys()?, ["public"]);
+        assert_eq!(config.own_property_names()?, ["secret", "public"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_array_length() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let array = evaluate_script(
+            &ctx,
+            "Array.from({ length: 1000 }, (_, i) => i)",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        assert_eq!(array.array_length()?, 1000);
+        assert_eq!(array.get_property_at_index(999).as_number()?, 999.);
+
+        let array_like =
+            evaluate_script(&ctx, "({ length: 2 })", None, "foo.js", 1)?.as_object()?;
+        assert_eq!(array_like.array_length()?, 2);
+
+        for not_array_like in [
+            "({})",
+            "({ length: -1 })",
+            "({ length: 1.5 })",
+            "({ length: '1' })",
+        ] {
+            let object = evaluate_script(&ctx, not_array_like, None, "foo.js", 1)?.as_object()?;
+            assert!(object.array_length().is_err());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_tag() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let shapes = evaluate_script(
+            &ctx,
+            "[{ type: 'circle', radius: 1 }, { type: 'square', side: 2 }, { type: 3 }, {}]",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        let circle = shapes.get_property_at_index(0).as_object()?;
+        assert_eq!(circle.tag("type")?, "circle");
+
+        let square = shapes.get_property_at_index(1).as_object()?;
+        assert_eq!(square.tag("type")?, "square");
+
+        let number = shapes.get_property_at_index(2).as_object()?;
+        assert!(number.tag("type").is_err());
+
+        let untagged = shapes.get_property_at_index(3).as_object()?;
+        assert!(untagged.tag("type").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_class_name() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let class_name = |script: &str| -> Result<Option<String>, JSException> {
+            Ok(evaluate_script(&ctx, script, None, "foo.js", 1)?
+                .as_object()?
+                .class_name())
+        };
+
+        assert_eq!(class_name("new Date(0)")?.as_deref(), Some("Date"));
+        assert_eq!(class_name("/a/")?.as_deref(), Some("RegExp"));
+        assert_eq!(class_name("[]")?.as_deref(), Some("Array"));
+        assert_eq!(class_name("({})")?.as_deref(), Some("Object"));
+        assert_eq!(class_name("new Map()")?.as_deref(), Some("Map"));
+        assert_eq!(
+            class_name("class Point {}; new Point()")?.as_deref(),
+            Some("Point")
+        );
+        assert_eq!(class_name("(() => {})")?.as_deref(), Some("Function"));
+
+        // Objects without a constructor.
+        assert_eq!(class_name("Object.create(null)")?, None);
+        assert_eq!(class_name("new (class {})()")?, None);
+        assert_eq!(class_name("({ constructor: 42 })")?, None);
+        assert_eq!(
+            class_name("({ get constructor() { throw new Error('oops') } })")?,
+            None
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_iterate() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let generator = evaluate_script(
+            &ctx,
+            "(function* () { yield 1; yield 'two'; yield 3; })()",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        let values = generator
+            .iter()?
+            .map(|value| value?.as_string().map(|value| value.to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(values, ["1", "two", "3"]);
+
+        // The generator is exhausted now.
+        assert_eq!(generator.iter()?.count(), 0);
+
+        let set =
+            evaluate_script(&ctx, "new Set(['a', 'b', 'a'])", None, "foo.js", 1)?.as_object()?;
+        let values = set
+            .iter()?
+            .map(|value| value?.as_string())
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(values, ["a", "b"]);
+
+        let array = JSValue::new_array(&ctx, &[JSValue::new_number(&ctx, 1.)])?.as_object()?;
+        assert_eq!(array.iter()?.count(), 1);
+
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+        assert!(object.iter().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_iterate_with_exceptions() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let generator = evaluate_script(
+            &ctx,
+            "(function* () { yield 1; throw new Error('boom'); })()",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        let mut iterator = generator.iter()?;
+        assert_eq!(iterator.next().unwrap()?.as_number()?, 1.);
+        assert_eq!(
+            iterator.next().unwrap().err().unwrap().to_string(),
+            "Error: boom"
+        );
+        assert!(iterator.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_stop_iterating_early() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let generator = evaluate_script(
+            &ctx,
+            "var cleaned = false; (function* () { try { yield 1; yield 2; } finally { cleaned = true; } })()",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        let mut iterator = generator.iter()?;
+        assert_eq!(iterator.next().unwrap()?.as_number()?, 1.);
+        drop(iterator);
+
+        let cleaned = evaluate_script(&ctx, "cleaned", None, "foo.js", 1)?;
+        assert!(cleaned.as_boolean());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_set_property() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = JSValue::new_from_json(&ctx, r#"{"foo": "bar"}"#)
+            .unwrap()
+            .as_object()?;
+
+        assert!(object.has_property("foo"));
+        assert!(!object.has_property("baz"));
+
+        object.set_property("baz", JSValue::new_string(&ctx, "qux"))?;
+
+        assert!(object.has_property("baz"));
+        assert_eq!(object.get_property("baz").as_string()?.to_string(), "qux");
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_get_and_set_prototype() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let base = JSValue::new_from_json(&ctx, r#"{"foo": "bar"}"#)
+            .unwrap()
+            .as_object()?;
+        let derived = JSValue::new_from_json(&ctx, r#"{"baz": "qux"}"#)
+            .unwrap()
+            .as_object()?;
+
+        assert!(!derived.has_property("foo"));
 
-        if result.is_null() {
-            return Err(JSValue::new_string_inner(
-                context,
-                "Cannot call this object as a function: it is not a valid function",
-            )
-            .into());
-        }
+        derived.set_prototype(&base);
 
-        Ok(unsafe { JSValue::from_raw(context, result) })
-    }
-}
+        assert_eq!(derived.prototype(), *base);
+        assert!(derived.has_property("foo"));
+        assert_eq!(derived.get_property("foo").as_string()?.to_string(), "bar");
+        assert!(derived.property_names().all(|name| name != "foo"));
 
-/// A `JSObject` can be dereferenced to return the underlying `JSValue`.
-///
-/// This lets a `JSObject` instance be used where a `JSValue` instance is
-/// expected.
-impl Deref for JSObject {
-    type Target = JSValue;
+        // A non-object prototype is ignored.
+        derived.set_prototype(&JSValue::new_number(&ctx, 1.0));
+        assert_eq!(derived.prototype(), *base);
 
-    fn deref(&self) -> &JSValue {
-        &self.value
-    }
-}
+        derived.set_prototype(&JSValue::new_null(&ctx));
 
-impl From<&JSObject> for JSValue {
-    fn from(object: &JSObject) -> Self {
-        // SAFETY: `ctx` and `raw` is valid, it's safe to use them.
-        unsafe { JSValue::from_raw(object.value.ctx, object.value.raw) }
-    }
-}
+        assert!(derived.prototype().is_null());
+        assert!(!derived.has_property("foo"));
+        assert!(!derived.has_property("hasOwnProperty"));
 
-impl From<JSObject> for JSValue {
-    fn from(object: JSObject) -> Self {
-        (&object).into()
+        Ok(())
     }
-}
 
-pub struct JSObjectPropertyNameIter {
-    raw: sys::JSPropertyNameArrayRef,
-    idx: usize,
-}
+    #[test]
+    fn can_freeze() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let config = JSValue::new_from_json(&ctx, r#"{"debug": false, "nested": {}}"#)
+            .unwrap()
+            .as_object()?;
 
-impl Iterator for JSObjectPropertyNameIter {
-    type Item = JSString;
+        assert!(!config.is_frozen()?);
+        config.freeze()?;
+        assert!(config.is_frozen()?);
+        assert!(config.is_sealed()?);
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.idx < unsafe { sys::JSPropertyNameArrayGetCount(self.raw) } {
-            let name = unsafe { sys::JSPropertyNameArrayGetNameAtIndex(self.raw, self.idx) };
-            self.idx += 1;
-            // GetNameAtIndex doesn't retain the name, so since we're going to release it
-            // when we release the property name array, but this JSString may outlive that,
-            // we need to retain the string to keep it alive.
-            Some(JSString {
-                raw: unsafe { sys::JSStringRetain(name) },
-            })
-        } else {
-            None
-        }
-    }
+        ctx.global_object()?
+            .set_property("config", (&config).into())?;
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let sz = unsafe { sys::JSPropertyNameArrayGetCount(self.raw) };
-        (sz - self.idx, Some(sz))
+        // Writes are silently ignored…
+        evaluate_script(
+            &ctx,
+            "config.debug = true; config.extra = 1",
+            None,
+            "foo.js",
+            1,
+        )?;
+        assert!(!config.get_property("debug").as_boolean());
+        assert!(!config.has_property("extra"));
+        assert!(!config.delete_property("debug")?);
+
+        // … or throw in strict mode.
+        let exception =
+            evaluate_script(&ctx, "'use strict'; config.debug = true", None, "foo.js", 1)
+                .unwrap_err();
+        assert!(exception.to_string().starts_with("TypeError"));
+
+        // Freezing is shallow.
+        let nested = config.get_property("nested").as_object()?;
+        assert!(!nested.is_frozen()?);
+
+        Ok(())
     }
-}
 
-impl Drop for JSObjectPropertyNameIter {
-    fn drop(&mut self) {
-        unsafe { sys::JSPropertyNameArrayRelease(self.raw) }
+    #[test]
+    fn can_seal() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = JSValue::new_from_json(&ctx, r#"{"a": 1}"#)
+            .unwrap()
+            .as_object()?;
+
+        object.seal()?;
+        assert!(object.is_sealed()?);
+        assert!(!object.is_frozen()?);
+
+        object.set_property("a", JSValue::new_number(&ctx, 2.))?;
+        object.set_property("b", JSValue::new_number(&ctx, 3.))?;
+        assert_eq!(object.get_property("a").as_number()?, 2.);
+        assert!(!object.has_property("b"));
+        assert!(!object.delete_property("a")?);
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::{JSContext, JSException, JSValue};
+    #[test]
+    fn can_get_and_set_property_for_key() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+        let key = JSValue::new_symbol(&ctx, "key");
+        let other_key = JSValue::new_symbol(&ctx, "key");
+
+        object.set_property_for_key(&key, JSValue::new_number(&ctx, 1.))?;
+
+        assert_eq!(object.get_property_for_key(&key)?.as_number()?, 1.);
+        assert!(object.get_property_for_key(&other_key)?.is_undefined());
+        assert!(!object.has_property("key"));
+
+        // Other keys are converted to strings.
+        object.set_property_for_key(
+            &JSValue::new_number(&ctx, 2.),
+            JSValue::new_boolean(&ctx, true),
+        )?;
+        assert!(object.get_property("2").as_boolean());
+
+        let proxy = evaluate_script(
+            &ctx,
+            "new Proxy({}, { get() { throw 'nope' }, set() { throw 'nope' } })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+        assert!(proxy.get_property_for_key(&key).is_err());
+        assert!(proxy
+            .set_property_for_key(&key, JSValue::new_null(&ctx))
+            .is_err());
+
+        Ok(())
+    }
 
     #[test]
-    fn can_has_property() {
+    fn can_delete_property() -> Result<(), JSException> {
         let ctx = JSContext::default();
-        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
-        let o = v.as_object().expect("object");
-        assert!(o.has_property("id"));
-        assert!(!o.has_property("no-such-value"));
+        let object = JSValue::new_from_json(&ctx, r#"{"foo": "bar"}"#)
+            .unwrap()
+            .as_object()?;
+
+        assert!(object.has_property("foo"));
+        assert!(object.delete_property("foo")?);
+        assert!(!object.has_property("foo"));
+
+        // Deleting a missing property succeeds too.
+        assert!(!object.has_property("baz"));
+        assert!(object.delete_property("baz")?);
+
+        let frozen =
+            evaluate_script(&ctx, "Object.freeze({ foo: 1 })", None, "foo.js", 1)?.as_object()?;
+        assert!(!frozen.delete_property("foo")?);
+        assert!(frozen.has_property("foo"));
+
+        let proxy = evaluate_script(
+            &ctx,
+            "new Proxy({}, { deleteProperty() { throw new Error('nope') } })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+        assert_eq!(
+            proxy.delete_property("foo").err().unwrap().to_string(),
+            "Error: nope"
+        );
+
+        Ok(())
     }
 
     #[test]
-    fn can_get_property() {
+    fn can_define_read_only_property() -> Result<(), JSException> {
         let ctx = JSContext::default();
-        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
-        let o = v.as_object().expect("object");
-        assert!(o.get_property("id").is_number());
-        assert!(o.get_property("no-such-value").is_undefined());
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+
+        object.define_property(
+            "ANSWER",
+            JSPropertyDescriptor::value(JSValue::new_number(&ctx, 42.))
+                .read_only()
+                .dont_enum()
+                .dont_delete(),
+        )?;
+        ctx.global_object()?.set_property("o", (&object).into())?;
+
+        assert_eq!(object.get_property("ANSWER").as_number()?, 42.);
+        assert_eq!(object.property_names().count(), 0);
+        assert!(!object.delete_property("ANSWER")?);
+
+        // Assignment fails silently, or throws in strict mode.
+        evaluate_script(&ctx, "o.ANSWER = 3", None, "foo.js", 1)?;
+        let exception = evaluate_script(&ctx, "'use strict'; o.ANSWER = 3", None, "foo.js", 1)
+            .err()
+            .unwrap();
+        assert!(exception.to_string().starts_with("TypeError"));
+        assert_eq!(object.get_property("ANSWER").as_number()?, 42.);
+
+        // It can't be redefined either.
+        let redefined = object.define_property(
+            "ANSWER",
+            JSPropertyDescriptor::value(JSValue::new_number(&ctx, 3.)),
+        );
+        assert!(redefined.is_err());
+
+        Ok(())
     }
 
     #[test]
-    fn can_get_property_at_index() {
+    fn can_redefine_property() -> Result<(), JSException> {
         let ctx = JSContext::default();
-        let v = JSValue::new_from_json(&ctx, "[3, true, \"abc\"]").expect("value");
-        let o = v.as_object().expect("object");
-        assert!(o.get_property_at_index(0).is_number());
-        assert!(o.get_property_at_index(1).is_boolean());
-        assert!(o.get_property_at_index(2).is_string());
-        assert!(o.get_property_at_index(5).is_undefined());
+        let object = JSValue::new_from_json(&ctx, r#"{"foo": 1}"#)
+            .unwrap()
+            .as_object()?;
+
+        object.define_property(
+            "foo",
+            JSPropertyDescriptor::value(JSValue::new_number(&ctx, 2.)).read_only(),
+        )?;
+
+        object.set_property("foo", JSValue::new_number(&ctx, 3.))?;
+        assert_eq!(object.get_property("foo").as_number()?, 2.);
+
+        Ok(())
     }
 
     #[test]
-    fn can_get_property_names() {
+    fn can_define_accessor_property() -> Result<(), JSException> {
         let ctx = JSContext::default();
-        let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("value");
-        let o = v.as_object().expect("object");
-        let names = o.property_names().collect::<Vec<_>>();
-        assert_eq!(names.len(), 1);
-        assert_eq!(names[0], "id");
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+        let stored = Rc::new(Cell::new(1.));
+
+        let getter = {
+            let stored = stored.clone();
+
+            ctx.make_function("get", move |ctx, _, _| {
+                Ok(JSValue::new_number(ctx, stored.get()))
+            })
+        };
+        let setter = {
+            let stored = stored.clone();
+
+            ctx.make_function("set", move |ctx, _, arguments| {
+                stored.set(arguments[0].as_number()?);
+
+                Ok(JSValue::new_undefined(ctx))
+            })
+        };
+
+        object.define_property(
+            "foo",
+            JSPropertyDescriptor::accessor(Some(getter), Some(setter)),
+        )?;
+
+        assert_eq!(object.get_property("foo").as_number()?, 1.);
+        object.set_property("foo", JSValue::new_number(&ctx, 2.))?;
+        assert_eq!(stored.get(), 2.);
+        assert_eq!(object.get_property("foo").as_number()?, 2.);
+
+        Ok(())
     }
 
     #[test]
-    fn can_set_property() -> Result<(), JSException> {
+    fn can_assign() -> Result<(), JSException> {
         let ctx = JSContext::default();
-        let object = JSValue::new_from_json(&ctx, r#"{"foo": "bar"}"#)
+        let target = JSValue::new_from_json(&ctx, r#"{"a": 0, "b": 0}"#)
             .unwrap()
             .as_object()?;
+        let first = JSValue::new_from_json(&ctx, r#"{"b": 1, "c": 1}"#)
+            .unwrap()
+            .as_object()?;
+        let second = evaluate_script(
+            &ctx,
+            "Object.defineProperty({ get c() { return 2 } }, 'hidden', { value: 2 })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
 
-        assert!(object.has_property("foo"));
-        assert!(!object.has_property("baz"));
+        target.assign(&[&first, &second])?;
 
-        object.set_property("baz", JSValue::new_string(&ctx, "qux"))?;
+        assert_eq!(target.get_property("a").as_number()?, 0.);
+        assert_eq!(target.get_property("b").as_number()?, 1.);
+        assert_eq!(target.get_property("c").as_number()?, 2.);
+        assert!(!target.has_property("hidden"));
 
-        assert!(object.has_property("baz"));
-        assert_eq!(object.get_property("baz").as_string()?.to_string(), "qux");
+        // The sources are left untouched.
+        assert_eq!(first.get_property("c").as_number()?, 1.);
+
+        let throwing = evaluate_script(&ctx, "({ get d() { throw 'nope' } })", None, "foo.js", 1)?
+            .as_object()?;
+        assert!(target.assign(&[&throwing]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_set_properties() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+
+        let properties = (1..=5)
+            .map(|i| (format!("p{i}"), JSValue::new_number(&ctx, f64::from(i))))
+            .collect::<HashMap<_, _>>();
+        object.set_properties(properties)?;
+
+        assert_eq!(object.keys()?.len(), 5);
+
+        for i in 1..=5 {
+            assert_eq!(
+                object.get_property(format!("p{i}")).as_number()?,
+                f64::from(i)
+            );
+        }
+
+        // Setting stops at the first exception.
+        let setter = evaluate_script(
+            &ctx,
+            "({ set a(_) { throw new Error('nope') } })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+        let result = setter.set_properties([
+            ("b", JSValue::new_number(&ctx, 1.)),
+            ("a", JSValue::new_number(&ctx, 2.)),
+            ("c", JSValue::new_number(&ctx, 3.)),
+        ]);
+        assert_eq!(result.unwrap_err().to_string(), "Error: nope");
+        assert!(setter.has_property("b"));
+        assert!(!setter.has_property("c"));
 
         Ok(())
     }
@@ -552,6 +2407,150 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn can_fill_array_by_index() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let array = JSValue::new_array(&ctx, &[])?.as_object()?;
+
+        for i in 0..3 {
+            array.set_property_at_index(i, JSValue::new_number(&ctx, f64::from(i * 10)))?;
+        }
+
+        assert_eq!(array.get_property("length").as_number()?, 3.);
+
+        for i in 0..3 {
+            assert_eq!(
+                array.try_get_property_at_index(i)?.as_number()?,
+                f64::from(i * 10)
+            );
+        }
+
+        assert!(array.try_get_property_at_index(3)?.is_undefined());
+
+        let object = evaluate_script(
+            &ctx,
+            "({ get 0() { throw new RangeError('nope') } })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        assert!(object.try_get_property_at_index(0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_own_array_buffer_bytes() -> Result<(), JSException> {
+        struct Bytes(Vec<u8>, Rc<Cell<bool>>);
+
+        impl AsMut<[u8]> for Bytes {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        impl Drop for Bytes {
+            fn drop(&mut self) {
+                self.1.set(true);
+            }
+        }
+
+        let dropped = Rc::new(Cell::new(false));
+
+        {
+            let ctx = JSContext::default();
+            let buffer = JSValue::new_array_buffer(&ctx, Bytes(vec![1, 2, 3], dropped.clone()))?;
+
+            assert!(buffer.is_array_buffer());
+            assert!(!buffer.is_typed_array());
+
+            ctx.global_object()?.set_property("buffer", buffer)?;
+            evaluate_script(&ctx, "new Uint8Array(buffer)[1] = 12", None, "foo.js", 1)?;
+
+            let buffer = ctx.global_object()?.get_property("buffer").as_object()?;
+            assert_eq!(unsafe { buffer.array_buffer_bytes() }?, &[1, 12, 3]);
+
+            // Still referenced by the global object.
+            garbage_collect(&ctx);
+            assert!(!dropped.get());
+
+            evaluate_script(&ctx, "delete globalThis.buffer", None, "foo.js", 1)?;
+            garbage_collect(&ctx);
+        }
+
+        // The garbage collector is conservative, so the bytes may be alive until
+        // the context is destroyed, but not longer.
+        assert!(dropped.get());
+
+        Ok(())
+    }
+
+    #[test]
+    fn cannot_get_array_buffer_bytes_of_other_objects() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let array = JSValue::new_array(&ctx, &[])?.as_object()?;
+
+        assert!(unsafe { array.array_buffer_bytes() }.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_use_private_data() -> Result<(), JSException> {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: f64,
+            y: f64,
+        }
+
+        let ctx = JSContext::default();
+        let class = JSClass::builder(&ctx, "Point")?.build()?;
+        let mut object = class.new_object();
+
+        assert!(object.private_data::<Point>().is_none());
+
+        object.set_private_data(Point { x: 1., y: 2. }).unwrap();
+        assert_eq!(object.private_data::<Point>().unwrap().y, 2.);
+
+        // Wrong type.
+        assert!(object.private_data::<f64>().is_none());
+        assert!(unsafe { object.private_data_mut::<f64>() }.is_none());
+
+        unsafe { object.private_data_mut::<Point>() }.unwrap().x = 3.;
+        assert_eq!(
+            object.private_data::<Point>(),
+            Some(&Point { x: 3., y: 2. })
+        );
+
+        // Already set.
+        assert_eq!(
+            object.set_private_data(Point { x: 0., y: 0. }),
+            Err(Point { x: 0., y: 0. })
+        );
+
+        // Not of a class.
+        let plain = JSValue::new_from_json(&ctx, "{}").unwrap().as_object()?;
+        assert_eq!(plain.set_private_data(1u8), Err(1u8));
+        assert!(plain.private_data::<u8>().is_none());
+
+        // Of a class which isn't of this crate, with private data of its own.
+        let mut number = 42u8;
+        let foreign_object = unsafe {
+            let foreign_class = sys::JSClassCreate(&sys::JSClassDefinition::default());
+            let foreign_object =
+                sys::JSObjectMake(ctx.raw, foreign_class, (&raw mut number).cast());
+            sys::JSClassRelease(foreign_class);
+
+            JSObject::from_raw(ctx.raw, foreign_object)
+        };
+        assert!(foreign_object.private_data::<u8>().is_none());
+        assert_eq!(foreign_object.set_private_data(1u8), Err(1u8));
+
+        Ok(())
+    }
+
     #[test]
     fn can_use_as_jsvalue_via_deref() {
         let ctx = JSContext::default();
@@ -578,6 +2577,84 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn can_construct_script_class() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let point = evaluate_script(
+            &ctx,
+            "class Point { constructor(x, y) { this.x = x; this.y = y; } norm() { return Math.hypot(this.x, this.y); } }; Point",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        assert!(point.is_constructor());
+
+        let instance = point
+            .call_as_constructor(&[JSValue::new_number(&ctx, 3.), JSValue::new_number(&ctx, 4.)])?
+            .as_object()?;
+
+        assert_eq!(instance.get_property("x").as_number()?, 3.);
+        assert_eq!(instance.get_property("y").as_number()?, 4.);
+
+        let norm = instance.get_property("norm").as_object()?;
+        assert_eq!(
+            norm.call_as_function(Some(&instance), &[])?.as_number()?,
+            5.
+        );
+
+        // A class can't be called as a function.
+        assert!(point.call_as_function(None, &[]).is_err());
+
+        // And a method can't be called as a constructor.
+        assert!(!norm.is_constructor());
+        assert!(norm.call_as_constructor(&[]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_call_script_function() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let function = evaluate_script(
+            &ctx,
+            "(function (a, b) { if (b === 0) throw new RangeError('zero'); return this.base + a / b; })",
+            None,
+            "foo.js",
+            1,
+        )?
+        .as_object()?;
+
+        assert!(function.is_function());
+
+        let this = JSValue::new_from_json(&ctx, r#"{"base": 100}"#)
+            .unwrap()
+            .as_object()?;
+        let result = function.call_as_function(
+            Some(&this),
+            &[JSValue::new_number(&ctx, 6.), JSValue::new_number(&ctx, 3.)],
+        )?;
+        assert_eq!(result.as_number()?, 102.);
+
+        // Without `this`, it's the global object, which has no `base`.
+        let result = function.call_as_function(
+            None,
+            &[JSValue::new_number(&ctx, 6.), JSValue::new_number(&ctx, 3.)],
+        )?;
+        assert!(result.as_number()?.is_nan());
+
+        let exception = function
+            .call_as_function(
+                Some(&this),
+                &[JSValue::new_number(&ctx, 6.), JSValue::new_number(&ctx, 0.)],
+            )
+            .unwrap_err();
+        assert_eq!(exception.name()?, "RangeError");
+
+        Ok(())
+    }
+
     #[test]
     fn can_call_as_function() -> Result<(), JSException> {
         let ctx = JSContext::default();
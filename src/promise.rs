@@ -0,0 +1,42 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{JSException, JSObject, JSValue, Protected};
+
+/// The functions resolving or rejecting a JavaScript promise.
+///
+/// It is created by [`JSContext::new_promise()`]. The functions are
+/// [protected](Protected) from garbage collection until the resolver is
+/// dropped, so it can be stored until the work backing the promise completes.
+///
+/// [`JSContext::new_promise()`]: crate::JSContext::new_promise
+pub struct JSPromiseResolver {
+    resolve: Protected<JSObject>,
+    reject: Protected<JSObject>,
+}
+
+impl JSPromiseResolver {
+    /// Create a new [`Self`] from the `resolve` and `reject` functions of a
+    /// promise.
+    pub(crate) fn new(resolve: JSObject, reject: JSObject) -> Self {
+        Self {
+            resolve: Protected::new(resolve),
+            reject: Protected::new(reject),
+        }
+    }
+
+    /// Resolves the promise with `value`.
+    ///
+    /// If `value` is itself a promise, or a thenable, the promise follows it.
+    pub fn resolve(self, value: JSValue) -> Result<(), JSException> {
+        self.resolve.call_as_function(None, &[value]).map(|_| ())
+    }
+
+    /// Rejects the promise with `reason`, usually an `Error`.
+    pub fn reject(self, reason: JSValue) -> Result<(), JSException> {
+        self.reject.call_as_function(None, &[reason]).map(|_| ())
+    }
+}
@@ -0,0 +1,243 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    class::{self, DeferredRelease},
+    sys, JSObject, JSValue,
+};
+use std::mem::ManuallyDrop;
+use std::ops::Deref;
+
+/// A guard keeping a [`JSValue`] (or a [`JSObject`]) alive through garbage
+/// collections.
+///
+/// The value is [protected](JSValue::protect) when the guard is created, and
+/// [unprotected](JSValue::unprotect) when the guard is dropped. Use it when a
+/// value outlives the stack frame that produced it, e.g. when storing it in a
+/// Rust data structure, where the garbage collector is not able to discover
+/// it.
+///
+/// Protection is scoped to the context group of the value: it doesn't keep the
+/// context itself alive, so the guard must not outlive the context.
+///
+/// A guard dropped by the finalizer of an object, as part of its [private
+/// data](crate::JSClass::new_object_with_private_data), can't unprotect its
+/// value right away, since JavaScriptCore can't be called from there: the
+/// value is unprotected the next time a context of its context group is used
+/// by this crate on the same thread, e.g. to evaluate a script or to protect
+/// another value. Until then, the value and what it references are kept
+/// alive.
+///
+/// A `Protected` can be dereferenced to its value.
+///
+/// ```rust
+/// # use javascriptcore::{garbage_collect, JSContext, JSValue, Protected};
+/// let ctx = JSContext::default();
+/// let names = vec![Protected::new(JSValue::new_string(&ctx, "Gordon"))];
+///
+/// garbage_collect(&ctx);
+///
+/// assert_eq!(names[0].as_string().unwrap(), "Gordon");
+/// ```
+pub struct Protected<T: AsRef<JSValue> = JSValue> {
+    value: T,
+    /// The context group of the value, to defer unprotecting it.
+    group: sys::JSContextGroupRef,
+}
+
+impl<T: AsRef<JSValue>> Protected<T> {
+    /// Protects `value` until the returned guard is dropped.
+    pub fn new(value: T) -> Self {
+        let ctx = value.as_ref().ctx;
+
+        class::run_deferred_releases(ctx);
+        value.as_ref().protect();
+
+        Self {
+            value,
+            group: unsafe { sys::JSContextGetGroup(ctx) },
+        }
+    }
+
+    /// Unprotects the value and returns it.
+    ///
+    /// The value is then again eligible for garbage collection, unless it is
+    /// referenced from JavaScript.
+    pub fn into_inner(self) -> T {
+        let this = ManuallyDrop::new(self);
+        this.value.as_ref().unprotect();
+
+        // SAFETY: `this` is never used nor dropped after this read.
+        unsafe { std::ptr::read(&this.value) }
+    }
+}
+
+impl<T: AsRef<JSValue>> Deref for Protected<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: AsRef<JSValue>> Drop for Protected<T> {
+    fn drop(&mut self) {
+        // JavaScriptCore can't be called from a finalizer.
+        if class::is_finalizing() {
+            class::defer_release(DeferredRelease::Unprotect(
+                self.group,
+                self.value.as_ref().raw,
+            ));
+
+            return;
+        }
+
+        self.value.as_ref().unprotect();
+    }
+}
+
+impl AsRef<JSValue> for JSValue {
+    fn as_ref(&self) -> &JSValue {
+        self
+    }
+}
+
+impl AsRef<JSValue> for JSObject {
+    fn as_ref(&self) -> &JSValue {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Protected;
+    use crate::{
+        evaluate_script, garbage_collect, sys, JSClass, JSContext, JSException, JSObject, JSValue,
+        WeakJSObject,
+    };
+
+    #[test]
+    fn protected_value_survives_garbage_collection() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        let values = (0..100)
+            .map(|i| {
+                evaluate_script(&ctx, format!("({{ i: {i} }})"), None, "foo.js", 1)
+                    .map(Protected::new)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Create some garbage.
+        evaluate_script(
+            &ctx,
+            "for (let i = 0; i < 10000; i++) { ({ i }) }",
+            None,
+            "foo.js",
+            1,
+        )?;
+        garbage_collect(&ctx);
+
+        for (i, value) in values.iter().enumerate() {
+            let object = value.as_object()?;
+            assert_eq!(object.get_property("i").as_number()?, i as f64);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn protected_object() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let object = Protected::new(
+            evaluate_script(&ctx, "({ foo: 'bar' })", None, "foo.js", 1)?.as_object()?,
+        );
+
+        garbage_collect(&ctx);
+
+        assert_eq!(object.get_property("foo").as_string()?, "bar");
+
+        let object = object.into_inner();
+        assert!(object.has_property("foo"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn protected_value_dropped_by_finalizer() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let class = JSClass::builder(&ctx, "Holder")?.build()?;
+
+        let weaks = (0..100)
+            .map(|i| {
+                let object = evaluate_script(&ctx, format!("({{ i: {i} }})"), None, "foo.js", 1)?
+                    .as_object()?;
+                let weak = WeakJSObject::new(&object);
+
+                // The holder is garbage right away, and only it references
+                // the object.
+                class.new_object_with_private_data(Protected::new(object));
+
+                Ok(weak)
+            })
+            .collect::<Result<Vec<_>, JSException>>()?;
+
+        // Finalizing the holders defers unprotecting the objects, until the
+        // next script is evaluated.
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+        evaluate_script(&ctx, "0", None, "foo.js", 1)?;
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+
+        // The garbage collector is conservative, so some objects may still be
+        // referenced from the stack, but not all of them.
+        assert!(weaks.iter().any(|weak| weak.upgrade(&ctx).is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn protected_value_dropped_by_array_buffer() -> Result<(), JSException> {
+        struct Bytes {
+            bytes: Vec<u8>,
+            _object: Protected<JSObject>,
+        }
+
+        impl AsMut<[u8]> for Bytes {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.bytes
+            }
+        }
+
+        let ctx = JSContext::default();
+
+        let weaks = (0..100)
+            .map(|i| {
+                let object = evaluate_script(&ctx, format!("({{ i: {i} }})"), None, "foo.js", 1)?
+                    .as_object()?;
+                let weak = WeakJSObject::new(&object);
+
+                // The buffer is garbage right away, and only its bytes
+                // reference the object.
+                let bytes = Bytes {
+                    bytes: vec![0; 8],
+                    _object: Protected::new(object),
+                };
+                JSValue::new_array_buffer(&ctx, bytes)?;
+
+                Ok(weak)
+            })
+            .collect::<Result<Vec<_>, JSException>>()?;
+
+        // Deallocating the bytes defers unprotecting the objects, until the
+        // next script is evaluated.
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+        evaluate_script(&ctx, "0", None, "foo.js", 1)?;
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+
+        assert!(weaks.iter().any(|weak| weak.upgrade(&ctx).is_none()));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,225 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{sys, JSContext, JSContextGroup, JSException, JSString, JSValue};
+use std::ptr;
+
+/// A JavaScript script, parsed once and evaluated any number of times.
+///
+/// Evaluating the same source with [`JSContext::evaluate_script()`] checks
+/// and copies it on every call. A `JSScript` does that once, when it's
+/// created, and can then be evaluated in any context of the same [context
+/// group](JSContextGroup), which also share JavaScriptCore's cache of
+/// compiled code.
+///
+/// The C API of JavaScriptCore doesn't expose its bytecode, so a `JSScript`
+/// can't be serialized and reloaded by another process: a cache of the
+/// compiled code only lives as long as its context group. Startup-time
+/// savings across processes aren't available through this type.
+///
+/// Scripts are classic scripts, not modules: `import` and `export`
+/// declarations are syntax errors, as the C API of JavaScriptCore has no
+/// module loader.
+///
+/// This type is part of the [private API](crate#private-api), from
+/// `JSScriptRefPrivate.h`.
+///
+/// ```rust
+/// # use javascriptcore::{JSContextGroup, JSScript};
+/// let group = JSContextGroup::new();
+/// let (first, second) = (group.new_context(), group.new_context());
+///
+/// let script = JSScript::new(&first, "6 * 7", Some("answer.js"), 1).unwrap();
+///
+/// assert_eq!(script.evaluate(&first).unwrap().as_number().unwrap(), 42.);
+/// assert_eq!(script.evaluate(&second).unwrap().as_number().unwrap(), 42.);
+/// ```
+pub struct JSScript {
+    raw: sys::JSScriptRef,
+    group: JSContextGroup,
+}
+
+impl JSScript {
+    /// Creates a script that can be evaluated in the contexts of the group
+    /// of `ctx`.
+    ///
+    /// * `ctx`: An execution context of the group to create the script for.
+    /// * `source`: A value that can be converted to a [`JSString`]
+    ///   containing the script.
+    /// * `source_url`: An optional URL for the script's source file. This
+    ///   is used by debuggers and when reporting exceptions. Pass `None`
+    ///   if you do not care to include source file information.
+    /// * `starting_line_number`: An integer value specifying the script's
+    ///   starting line number in the file located at `source_url`. This
+    ///   is only used when reporting exceptions. The value is one-based,
+    ///   so the first line is line `1` and invalid values are clamped
+    ///   to `1`.
+    ///
+    /// Returns the `SyntaxError` [exception](JSException) if the script has
+    /// a syntax error, whose `message` and `line` properties describe it,
+    /// like [`JSContext::check_syntax()`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSScript};
+    /// let ctx = JSContext::default();
+    ///
+    /// let exception = JSScript::new(&ctx, "1 +", None, 1).err().unwrap();
+    /// assert_eq!(exception.name().unwrap(), "SyntaxError");
+    /// ```
+    pub fn new<S: Into<JSString>>(
+        ctx: &JSContext,
+        source: S,
+        source_url: Option<&str>,
+        starting_line_number: i32,
+    ) -> Result<Self, JSException> {
+        let group = ctx.group();
+        let source = source.into();
+        let source_url = source_url.map(JSString::from);
+        let mut error_message: sys::JSStringRef = ptr::null_mut();
+        let mut error_line = 0;
+
+        let raw = unsafe {
+            sys::JSScriptCreateFromString(
+                group.raw,
+                source_url.as_ref().map_or(ptr::null_mut(), |u| u.raw),
+                starting_line_number,
+                source.raw,
+                &mut error_message,
+                &mut error_line,
+            )
+        };
+
+        // SAFETY: The error message, if any, is owned by the caller.
+        let error_message = unsafe { JSString::from_raw(error_message) };
+
+        if raw.is_null() {
+            // Like the `SyntaxError` thrown when evaluating the script.
+            let error = ctx.new_error_of_type(
+                "SyntaxError",
+                error_message.unwrap_or_else(|| JSString::from("")),
+            )?;
+            error.set_property("line", JSValue::new_number(ctx, error_line.into()))?;
+
+            if let Some(source_url) = source_url {
+                error.set_property("sourceURL", JSValue::new_string(ctx, source_url))?;
+            }
+
+            return Err(JSValue::from(error).into());
+        }
+
+        Ok(Self { raw, group })
+    }
+
+    /// Evaluates this script in a context, with the global object as `this`.
+    ///
+    /// `ctx` must be in the context group the script was created for.
+    ///
+    /// Returns either the [`JSValue`] that results from evaluating the script,
+    /// or the [exception](JSException) that was thrown, including when `ctx`
+    /// is in another context group.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSScript};
+    /// let ctx = JSContext::default();
+    /// let script = JSScript::new(&ctx, "this.count = (this.count ?? 0) + 1", None, 1).unwrap();
+    ///
+    /// script.evaluate(&ctx).unwrap();
+    /// assert_eq!(script.evaluate(&ctx).unwrap().as_number().unwrap(), 2.);
+    ///
+    /// assert!(script.evaluate(&JSContext::default()).is_err());
+    /// ```
+    pub fn evaluate(&self, ctx: &JSContext) -> Result<JSValue, JSException> {
+        // JavaScriptCore aborts on scripts evaluated in another group.
+        if ctx.group().raw != self.group.raw {
+            return Err(JSValue::new_string_inner(
+                ctx.raw,
+                "Script can't be evaluated in another context group",
+            )
+            .into());
+        }
+
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let result =
+            unsafe { sys::JSScriptEvaluate(ctx.raw, self.raw, ptr::null_mut(), &mut exception) };
+
+        if result.is_null() {
+            return Err(unsafe { JSValue::from_raw(ctx.raw, exception) }.into());
+        }
+
+        Ok(unsafe { JSValue::from_raw(ctx.raw, result) })
+    }
+}
+
+impl Clone for JSScript {
+    fn clone(&self) -> Self {
+        unsafe { sys::JSScriptRetain(self.raw) };
+
+        Self {
+            raw: self.raw,
+            group: JSContextGroup {
+                raw: unsafe { sys::JSContextGroupRetain(self.group.raw) },
+            },
+        }
+    }
+}
+
+impl Drop for JSScript {
+    fn drop(&mut self) {
+        unsafe { sys::JSScriptRelease(self.raw) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::JSScript;
+    use crate::{JSContext, JSContextGroup, JSException};
+
+    #[test]
+    fn evaluate_twice() -> Result<(), JSException> {
+        let group = JSContextGroup::new();
+        let first = group.new_context();
+        let second = group.new_context();
+
+        let script = JSScript::new(&first, "this.runs = (this.runs ?? 0) + 1", None, 1)?;
+
+        assert_eq!(script.evaluate(&first)?.as_number()?, 1.);
+        assert_eq!(script.evaluate(&first)?.as_number()?, 2.);
+        assert_eq!(script.clone().evaluate(&second)?.as_number()?, 1.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn errors() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        let error = JSScript::new(&ctx, "\n1 +", Some("broken.js"), 1)
+            .err()
+            .unwrap();
+        assert_eq!(error.name()?, "SyntaxError");
+        assert!(!error.message().unwrap().is_empty());
+
+        let error = error.underlying_value().as_object()?;
+        assert_eq!(error.get_property("line").as_number()?, 2.);
+        assert_eq!(error.get_property("sourceURL").as_string()?, "broken.js");
+
+        let script = JSScript::new(&ctx, "throw new Error('oops')", None, 1)?;
+        let error = script.evaluate(&ctx).unwrap_err();
+        assert_eq!(error.message().unwrap(), "oops");
+
+        assert!(script.evaluate(&JSContext::default()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn modules_are_not_supported() {
+        let ctx = JSContext::default();
+
+        assert!(JSScript::new(&ctx, "export const a = 1;", None, 1).is_err());
+        assert!(JSScript::new(&ctx, "import { a } from './a.js';", None, 1).is_err());
+    }
+}
@@ -0,0 +1,1027 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    sys, JSContext, JSException, JSObject, JSPropertyDescriptor, JSString, JSType, JSValue,
+};
+use serde::de::{self, DeserializeOwned, IntoDeserializer};
+use serde::ser::{self, Serialize};
+use std::{fmt, ptr};
+use thiserror::Error;
+
+/// The maximum depth of nested arrays and objects [`from_jsvalue()`]
+/// deserializes, which stops it on circular objects.
+const MAX_DEPTH: usize = 128;
+
+/// An error returned by [`to_jsvalue()`] and [`from_jsvalue()`].
+#[derive(Debug, Error)]
+pub enum JSSerdeError {
+    /// An exception was thrown, e.g. by a getter.
+    #[error("{0}")]
+    Exception(#[from] JSException),
+
+    /// The value can't be converted, e.g. it doesn't match the expected
+    /// Rust type.
+    #[error("{0}")]
+    Message(String),
+}
+
+impl ser::Error for JSSerdeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Message(message.to_string())
+    }
+}
+
+impl de::Error for JSSerdeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self::Message(message.to_string())
+    }
+}
+
+/// Converts a Rust value into a [`JSValue`] in the context `ctx`, with its
+/// [`Serialize`] implementation.
+///
+/// * Booleans and strings become booleans and strings, as do `char`s.
+/// * Numbers become numbers. JavaScript numbers are `f64`s: integers whose
+///   magnitude is beyond 2<sup>53</sup>, like some `i64` and `u64`, are
+///   rounded to the nearest `f64`, as with `as f64`.
+/// * `None` and `()` become `null`, `Some` becomes its value.
+/// * Sequences and tuples become arrays.
+/// * Maps and structs become objects. Map keys must be strings or numbers,
+///   which are converted to strings.
+/// * Enum variants are externally tagged, like with `serde_json`: a unit
+///   variant becomes its name, other variants become an object whose only
+///   property is named after the variant.
+///
+/// Returns an [error](JSSerdeError) if the value can't be converted, e.g.
+/// if a map has a key which isn't a string nor a number.
+///
+/// ```rust
+/// # use javascriptcore::{to_jsvalue, JSContext};
+/// #[derive(serde::Serialize)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let ctx = JSContext::default();
+/// let value = to_jsvalue(&ctx, &[Point { x: 1., y: 2. }]).unwrap();
+///
+/// assert_eq!(value.to_json_string(0).unwrap(), r#"[{"x":1,"y":2}]"#);
+/// ```
+///
+/// # See also
+///
+/// * [`from_jsvalue()`]
+pub fn to_jsvalue<T>(ctx: &JSContext, value: &T) -> Result<JSValue, JSSerdeError>
+where
+    T: Serialize + ?Sized,
+{
+    value.serialize(Serializer { ctx })
+}
+
+/// Converts a [`JSValue`] into a Rust value, with its [`Deserialize`]
+/// implementation.
+///
+/// It converts back what [`to_jsvalue()`] creates:
+///
+/// * Booleans and strings are converted from booleans and strings.
+/// * Numbers are converted from numbers. Integers are only converted from
+///   numbers without a fractional part, which fit in the integer type.
+/// * `None` is converted from `null` or `undefined`, `()` too.
+/// * Sequences and tuples are converted from arrays.
+/// * Maps and structs are converted from objects, with their own enumerable
+///   properties, like `Object.keys()` lists them. Integer map keys are
+///   parsed from the names of the properties.
+/// * Enum variants are converted from their name, or from an object whose
+///   only property is named after the variant.
+///
+/// Returns an [error](JSSerdeError) if the value doesn't match the type `T`,
+/// if it contains a symbol or a `BigInt`, if it nests arrays and objects
+/// too deeply, like circular objects do, or if an exception was thrown,
+/// e.g. by a getter.
+///
+/// ```rust
+/// # use javascriptcore::{from_jsvalue, JSContext};
+/// #[derive(serde::Deserialize)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// let ctx = JSContext::default();
+/// let value = ctx.evaluate_script("({ x: 1, y: 2 })", None, 1).unwrap();
+/// let point: Point = from_jsvalue(&value).unwrap();
+///
+/// assert_eq!((point.x, point.y), (1., 2.));
+/// ```
+///
+/// # See also
+///
+/// * [`to_jsvalue()`]
+///
+/// [`Deserialize`]: serde::Deserialize
+pub fn from_jsvalue<T: DeserializeOwned>(value: &JSValue) -> Result<T, JSSerdeError> {
+    T::deserialize(Deserializer {
+        value: copy(value),
+        depth: 0,
+    })
+}
+
+/// Copies a value, to own it.
+fn copy(value: &JSValue) -> JSValue {
+    // SAFETY: `ctx` and `raw` are valid, it's safe to use them.
+    unsafe { JSValue::from_raw(value.ctx, value.raw) }
+}
+
+/// Creates an empty object, like `{}`.
+fn new_object(ctx: &JSContext) -> JSObject {
+    unsafe {
+        JSObject::from_raw(
+            ctx.raw,
+            sys::JSObjectMake(ctx.raw, ptr::null_mut(), ptr::null_mut()),
+        )
+    }
+}
+
+/// Creates an object whose only property is named after `variant`, for
+/// the variants of enums which aren't unit variants.
+fn new_variant_object(
+    ctx: &JSContext,
+    variant: &'static str,
+    value: JSValue,
+) -> Result<JSValue, JSSerdeError> {
+    let object = new_object(ctx);
+    object.define_property(variant, JSPropertyDescriptor::value(value))?;
+
+    Ok(object.into())
+}
+
+/// A serializer into [`JSValue`]s, see [`to_jsvalue()`].
+struct Serializer<'a> {
+    ctx: &'a JSContext,
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    type SerializeSeq = SerializeArray<'a>;
+    type SerializeTuple = SerializeArray<'a>;
+    type SerializeTupleStruct = SerializeArray<'a>;
+    type SerializeTupleVariant = SerializeArray<'a>;
+    type SerializeMap = SerializeObject<'a>;
+    type SerializeStruct = SerializeObject<'a>;
+    type SerializeStructVariant = SerializeObject<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<JSValue, JSSerdeError> {
+        Ok(JSValue::new_boolean(self.ctx, v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<JSValue, JSSerdeError> {
+        // Rounded beyond 2^53.
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<JSValue, JSSerdeError> {
+        // Rounded beyond 2^53.
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<JSValue, JSSerdeError> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<JSValue, JSSerdeError> {
+        Ok(JSValue::new_number(self.ctx, v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<JSValue, JSSerdeError> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<JSValue, JSSerdeError> {
+        Ok(JSValue::new_string(self.ctx, v))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<JSValue, JSSerdeError> {
+        // An array of numbers, like other sequences.
+        ser::Serializer::collect_seq(self, v)
+    }
+
+    fn serialize_none(self) -> Result<JSValue, JSSerdeError> {
+        Ok(JSValue::new_null(self.ctx))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<JSValue, JSSerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<JSValue, JSSerdeError> {
+        Ok(JSValue::new_null(self.ctx))
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<JSValue, JSSerdeError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<JSValue, JSSerdeError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<JSValue, JSSerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<JSValue, JSSerdeError> {
+        let ctx = self.ctx;
+
+        new_variant_object(ctx, variant, value.serialize(self)?)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeArray<'a>, JSSerdeError> {
+        Ok(SerializeArray {
+            ctx: self.ctx,
+            items: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeArray<'a>, JSSerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<'a>, JSSerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeArray<'a>, JSSerdeError> {
+        Ok(SerializeArray {
+            ctx: self.ctx,
+            items: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<SerializeObject<'a>, JSSerdeError> {
+        Ok(SerializeObject {
+            ctx: self.ctx,
+            object: new_object(self.ctx),
+            key: None,
+            variant: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject<'a>, JSSerdeError> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<SerializeObject<'a>, JSSerdeError> {
+        Ok(SerializeObject {
+            ctx: self.ctx,
+            object: new_object(self.ctx),
+            key: None,
+            variant: Some(variant),
+        })
+    }
+}
+
+/// Serializes sequences and tuples into arrays.
+struct SerializeArray<'a> {
+    ctx: &'a JSContext,
+    items: Vec<JSValue>,
+    /// The variant of a tuple variant.
+    variant: Option<&'static str>,
+}
+
+impl SerializeArray<'_> {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        self.items
+            .push(value.serialize(Serializer { ctx: self.ctx })?);
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<JSValue, JSSerdeError> {
+        let array = JSValue::new_array(self.ctx, &self.items)?;
+
+        match self.variant {
+            Some(variant) => new_variant_object(self.ctx, variant, array),
+            None => Ok(array),
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeArray<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeArray<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeArray<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeArray<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+/// Serializes maps and structs into objects.
+struct SerializeObject<'a> {
+    ctx: &'a JSContext,
+    object: JSObject,
+    /// The key of the entry being serialized, for maps.
+    key: Option<JSString>,
+    /// The variant of a struct variant.
+    variant: Option<&'static str>,
+}
+
+impl SerializeObject<'_> {
+    fn set<T: Serialize + ?Sized>(&self, key: JSString, value: &T) -> Result<(), JSSerdeError> {
+        let value = value.serialize(Serializer { ctx: self.ctx })?;
+        // Defined rather than set, so that a `__proto__` key is an own
+        // property, as with `JSON.parse()`, rather than the prototype.
+        self.object
+            .define_property(key, JSPropertyDescriptor::value(value))?;
+
+        Ok(())
+    }
+
+    fn finish(self) -> Result<JSValue, JSSerdeError> {
+        match self.variant {
+            Some(variant) => new_variant_object(self.ctx, variant, self.object.into()),
+            None => Ok(self.object.into()),
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeObject<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), JSSerdeError> {
+        let key = key.serialize(Serializer { ctx: self.ctx })?;
+
+        if !key.is_string() && !key.is_number() {
+            return Err(JSSerdeError::Message(
+                "map keys must be strings or numbers".to_owned(),
+            ));
+        }
+
+        self.key = Some(key.as_string()?);
+
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), JSSerdeError> {
+        let key = self
+            .key
+            .take()
+            .expect("`serialize_key` is called before `serialize_value`");
+
+        self.set(key, value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), JSSerdeError> {
+        self.set(key.into(), value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeObject<'_> {
+    type Ok = JSValue;
+    type Error = JSSerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), JSSerdeError> {
+        self.set(key.into(), value)
+    }
+
+    fn end(self) -> Result<JSValue, JSSerdeError> {
+        self.finish()
+    }
+}
+
+/// A deserializer from a [`JSValue`], see [`from_jsvalue()`].
+struct Deserializer {
+    value: JSValue,
+    /// The number of arrays and objects `value` is nested in.
+    depth: usize,
+}
+
+impl Deserializer {
+    /// Creates a deserializer for a value nested in the current one.
+    fn nested(&self, value: JSValue) -> Result<Self, JSSerdeError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(JSSerdeError::Message(
+                "arrays and objects are nested too deeply, or circular".to_owned(),
+            ));
+        }
+
+        Ok(Self {
+            value,
+            depth: self.depth + 1,
+        })
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer {
+    type Error = JSSerdeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, JSSerdeError> {
+        match self.value.get_type() {
+            JSType::Undefined | JSType::Null => visitor.visit_unit(),
+            JSType::Boolean => visitor.visit_bool(self.value.as_boolean()),
+            JSType::Number => {
+                let number = self.value.as_number()?;
+
+                // Integers are visited as such, so that integer types accept
+                // them.
+                if number.fract() == 0. && number.abs() <= 2f64.powi(53) {
+                    if number >= 0. {
+                        visitor.visit_u64(number as u64)
+                    } else {
+                        visitor.visit_i64(number as i64)
+                    }
+                } else {
+                    visitor.visit_f64(number)
+                }
+            }
+            JSType::String => visitor.visit_string(self.value.as_string()?.to_string()),
+            JSType::Object if self.value.is_array() => {
+                let array = self.value.as_object()?;
+                let length = array.array_length()?;
+
+                visitor.visit_seq(ArrayAccess {
+                    deserializer: self,
+                    array,
+                    index: 0,
+                    length,
+                })
+            }
+            JSType::Object => {
+                let object = self.value.as_object()?;
+                let keys = object.keys()?.into_iter();
+
+                visitor.visit_map(ObjectAccess {
+                    deserializer: self,
+                    object,
+                    keys,
+                    value: None,
+                })
+            }
+            JSType::Symbol => Err(JSSerdeError::Message(
+                "symbols can't be deserialized".to_owned(),
+            )),
+            _ => Err(JSSerdeError::Message(format!(
+                "values of type `{}` can't be deserialized",
+                self.value.type_of()
+            ))),
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, JSSerdeError> {
+        if self.value.is_nullish() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        // A unit variant.
+        if self.value.is_string() {
+            let variant = self.value.as_string()?.to_string();
+
+            return visitor.visit_enum(variant.into_deserializer());
+        }
+
+        // Another variant, the only property of an object.
+        if self.value.is_object() && !self.value.is_array() {
+            let object = self.value.as_object()?;
+            let mut keys = object.keys()?;
+
+            if keys.len() == 1 {
+                let variant = keys.remove(0);
+                let name = variant.to_string();
+                let value = self.nested(object.try_get_property(variant)?)?;
+
+                return visitor.visit_enum(VariantAccess {
+                    variant: name,
+                    deserializer: value,
+                });
+            }
+        }
+
+        Err(JSSerdeError::Message(
+            "an enum variant must be a string, or an object with a single property".to_owned(),
+        ))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+/// Visits the items of an array.
+struct ArrayAccess {
+    deserializer: Deserializer,
+    array: JSObject,
+    index: u32,
+    length: u32,
+}
+
+impl<'de> de::SeqAccess<'de> for ArrayAccess {
+    type Error = JSSerdeError;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, JSSerdeError> {
+        if self.index >= self.length {
+            return Ok(None);
+        }
+
+        let item = self.array.try_get_property_at_index(self.index)?;
+        self.index += 1;
+
+        seed.deserialize(self.deserializer.nested(item)?).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.length - self.index) as usize)
+    }
+}
+
+/// Visits the own enumerable properties of an object.
+struct ObjectAccess {
+    deserializer: Deserializer,
+    object: JSObject,
+    keys: std::vec::IntoIter<JSString>,
+    /// The value of the last visited key.
+    value: Option<JSValue>,
+}
+
+impl<'de> de::MapAccess<'de> for ObjectAccess {
+    type Error = JSSerdeError;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, JSSerdeError> {
+        let Some(key) = self.keys.next() else {
+            return Ok(None);
+        };
+
+        let name = key.to_string();
+        self.value = Some(self.object.try_get_property(key)?);
+
+        seed.deserialize(KeyDeserializer { key: name }).map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        let value = self
+            .value
+            .take()
+            .expect("`next_key_seed` is called before `next_value_seed`");
+
+        seed.deserialize(self.deserializer.nested(value)?)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.keys.len())
+    }
+}
+
+/// Visits the variant of an enum, and its value.
+struct VariantAccess {
+    variant: String,
+    deserializer: Deserializer,
+}
+
+impl<'de> de::EnumAccess<'de> for VariantAccess {
+    type Error = JSSerdeError;
+    type Variant = Deserializer;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Deserializer), JSSerdeError> {
+        let deserializer: de::value::StringDeserializer<JSSerdeError> =
+            self.variant.into_deserializer();
+        let variant = seed.deserialize(deserializer)?;
+
+        Ok((variant, self.deserializer))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for Deserializer {
+    type Error = JSSerdeError;
+
+    fn unit_variant(self) -> Result<(), JSSerdeError> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, JSSerdeError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
+
+/// A deserializer from the name of a property, which parses it for integer
+/// types, since [`to_jsvalue()`] converts integer map keys to strings.
+struct KeyDeserializer {
+    key: String,
+}
+
+macro_rules! deserialize_integer_key {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, JSSerdeError> {
+                match self.key.parse() {
+                    Ok(integer) => visitor.$visit(integer),
+                    Err(_) => visitor.visit_string(self.key),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> de::Deserializer<'de> for KeyDeserializer {
+    type Error = JSSerdeError;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, JSSerdeError> {
+        visitor.visit_string(self.key)
+    }
+
+    deserialize_integer_key! {
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, JSSerdeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool f32 f64 char str string bytes byte_buf option unit unit_struct
+        seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_jsvalue, to_jsvalue, JSSerdeError};
+    use crate::JSContext;
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Segment(Point, Point),
+        Rectangle { origin: Point, size: (u32, u32) },
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Drawing {
+        name: String,
+        origin: Point,
+        points: Vec<Point>,
+        shapes: Vec<Shape>,
+        tags: BTreeMap<String, u8>,
+        layers: BTreeMap<u32, bool>,
+        author: Option<String>,
+        id: i64,
+    }
+
+    #[test]
+    fn round_trip() -> Result<(), JSSerdeError> {
+        let ctx = JSContext::default();
+        let drawing = Drawing {
+            name: "sketch ✏️".to_owned(),
+            origin: Point { x: 0.5, y: -1. },
+            points: vec![Point { x: 1., y: 2. }, Point { x: 3., y: 4. }],
+            shapes: vec![
+                Shape::Empty,
+                Shape::Circle(2.),
+                Shape::Segment(Point { x: 0., y: 0. }, Point { x: 1., y: 1. }),
+                Shape::Rectangle {
+                    origin: Point { x: 0., y: 0. },
+                    size: (3, 4),
+                },
+            ],
+            tags: BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+            layers: BTreeMap::from([(1, true), (20, false)]),
+            author: None,
+            id: -42,
+        };
+
+        let value = to_jsvalue(&ctx, &drawing)?;
+        assert_eq!(from_jsvalue::<Drawing>(&value)?, drawing);
+
+        // Nested structs and vectors are objects and arrays.
+        let object = value.as_object()?;
+        assert_eq!(
+            object
+                .get_property("origin")
+                .as_object()?
+                .get_property("x")
+                .as_number()?,
+            0.5
+        );
+        assert!(object.get_property("points").is_array());
+        assert!(object.get_property("author").is_null());
+        assert_eq!(
+            object.get_property("shapes").to_json_string(0)?,
+            r#"["Empty",{"Circle":2},{"Segment":[{"x":0,"y":0},{"x":1,"y":1}]},{"Rectangle":{"origin":{"x":0,"y":0},"size":[3,4]}}]"#
+        );
+        assert_eq!(
+            object.get_property("layers").to_json_string(0)?,
+            r#"{"1":true,"20":false}"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_javascript() -> Result<(), JSSerdeError> {
+        let ctx = JSContext::default();
+
+        let value = ctx.evaluate_script(
+            "({ x: 1, y: 2, ignored: 'yes', get computed() { return 3 } })",
+            None,
+            1,
+        )?;
+        assert_eq!(from_jsvalue::<Point>(&value)?, Point { x: 1., y: 2. });
+        assert_eq!(
+            from_jsvalue::<BTreeMap<String, f64>>(&value)
+                .unwrap_err()
+                .to_string(),
+            "invalid type: string \"yes\", expected f64"
+        );
+
+        // `undefined` and missing properties are `None`.
+        let value = ctx.evaluate_script("[undefined, null, 1]", None, 1)?;
+        assert_eq!(
+            from_jsvalue::<Vec<Option<u8>>>(&value)?,
+            [None, None, Some(1)]
+        );
+
+        // Integers must be exact, and in range.
+        let value = ctx.evaluate_script("[1.5]", None, 1)?;
+        assert!(from_jsvalue::<Vec<u8>>(&value).is_err());
+        let value = ctx.evaluate_script("[256]", None, 1)?;
+        assert!(from_jsvalue::<Vec<u8>>(&value).is_err());
+        let value = ctx.evaluate_script("[-1]", None, 1)?;
+        assert!(from_jsvalue::<Vec<u8>>(&value).is_err());
+
+        // Exceptions thrown by getters are returned.
+        let value = ctx.evaluate_script("({ get x() { throw new Error('oops') } })", None, 1)?;
+        assert!(matches!(
+            from_jsvalue::<Point>(&value),
+            Err(JSSerdeError::Exception(_))
+        ));
+
+        // Circular objects are too deep.
+        let value = ctx.evaluate_script("const a = { a: null }; a.a = a; a", None, 1)?;
+        assert!(from_jsvalue::<serde::de::IgnoredAny>(&value).is_err());
+
+        let value = ctx.evaluate_script("Symbol()", None, 1)?;
+        assert!(from_jsvalue::<serde::de::IgnoredAny>(&value).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn numbers() -> Result<(), JSSerdeError> {
+        let ctx = JSContext::default();
+
+        // Integers beyond 2^53 are rounded.
+        let value = to_jsvalue(&ctx, &(u64::MAX, 2i64.pow(53) + 1))?;
+        assert_eq!(
+            from_jsvalue::<(f64, i64)>(&value)?,
+            (u64::MAX as f64, 2i64.pow(53))
+        );
+
+        let value = to_jsvalue(&ctx, &(0.1f32, f64::INFINITY, 'c', ()))?;
+        assert_eq!(
+            value.to_json_string(0)?,
+            r#"[0.10000000149011612,null,"c",null]"#
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn proto_keys() -> Result<(), JSSerdeError> {
+        let ctx = JSContext::default();
+
+        let map = BTreeMap::from([("__proto__".to_owned(), 1), ("a".to_owned(), 2)]);
+        let value = to_jsvalue(&ctx, &map)?;
+        assert_eq!(from_jsvalue::<BTreeMap<String, i32>>(&value)?, map);
+
+        // The key is an own property, the prototype is untouched.
+        let object = value.as_object()?;
+        assert_eq!(object.get_property("__proto__").as_number()?, 1.);
+        assert_eq!(value.to_json_string(0)?, r#"{"__proto__":1,"a":2}"#);
+
+        let value = ctx.evaluate_script(r#"JSON.parse('{"__proto__": 1}')"#, None, 1)?;
+        assert_eq!(
+            from_jsvalue::<BTreeMap<String, i32>>(&value)?,
+            BTreeMap::from([("__proto__".to_owned(), 1)])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_map_keys() {
+        let ctx = JSContext::default();
+
+        let map = BTreeMap::from([((1, 2), true)]);
+        assert!(to_jsvalue(&ctx, &map).is_err());
+    }
+}
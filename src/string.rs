@@ -5,10 +5,64 @@
 // except according to those terms.
 
 use crate::{sys, JSString};
-use std::ffi::CString;
-use std::fmt;
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::num::{ParseFloatError, ParseIntError};
+use std::ops::{Add, AddAssign, Deref};
+use std::{fmt, slice, str};
+use thiserror::Error;
 
 impl JSString {
+    /// Create a new [`Self`] from its raw pointer, or return `None` if it is
+    /// null.
+    ///
+    /// The new `JSString` takes ownership of the reference held by `raw`,
+    /// and releases it when dropped: call [`sys::JSStringRetain()`] first to
+    /// keep using `raw` afterwards.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{sys, JSString};
+    /// let raw = unsafe { sys::JSStringCreateWithCharacters([0x61].as_ptr(), 1) };
+    /// let str = unsafe { JSString::from_raw(raw) }.unwrap();
+    /// assert_eq!(str, "a");
+    ///
+    /// assert!(unsafe { JSString::from_raw(std::ptr::null_mut()) }.is_none());
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Ensure `raw` is either null, or valid and owned by the caller.
+    pub unsafe fn from_raw(raw: sys::JSStringRef) -> Option<Self> {
+        if raw.is_null() {
+            None
+        } else {
+            Some(Self { raw })
+        }
+    }
+
+    /// Create a JavaScript string from a buffer of UTF-16 code units.
+    ///
+    /// The code units are copied as is, without any validation. Contrary
+    /// to the conversion from `&str`, this allows to build strings that
+    /// can't be represented in UTF-8, like strings containing unpaired
+    /// surrogates.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from_utf16(&[0x61, 0xd83d, 0xde04]);
+    ///
+    /// assert_eq!(str.len(), 3);
+    /// assert_eq!(str, "a😄");
+    /// ```
+    pub fn from_utf16(units: &[u16]) -> Self {
+        Self {
+            raw: unsafe { sys::JSStringCreateWithCharacters(units.as_ptr(), units.len()) },
+        }
+    }
+
     /// Return the number of Unicode characters in this JavaScript string.
     ///
     /// Remember that strings in JavaScript are UTF-16 encoded.
@@ -37,8 +91,275 @@ impl JSString {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Return an iterator over the UTF-16 code units of this JavaScript string.
+    ///
+    /// The code units are read directly from the string's backing store,
+    /// so no UTF-8 conversion or allocation happens. Characters outside
+    /// the Basic Multilingual Plane are yielded as two code units (a
+    /// surrogate pair).
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("a😄");
+    /// let units = str.chars().collect::<Vec<u16>>();
+    ///
+    /// assert_eq!(units, &[0x61, 0xd83d, 0xde04]);
+    /// ```
+    pub fn chars(&self) -> JSStringChars<'_> {
+        JSStringChars {
+            inner: self.as_utf16().iter(),
+        }
+    }
+
+    /// Return a copy of the UTF-16 code units of this JavaScript string.
+    ///
+    /// This is a shortcut for `self.chars().collect::<Vec<u16>>()`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("abc");
+    ///
+    /// assert_eq!(str.code_units(), &[0x61, 0x62, 0x63]);
+    /// ```
+    pub fn code_units(&self) -> Vec<u16> {
+        self.as_utf16().to_vec()
+    }
+
+    /// Concatenate JavaScript strings into a new JavaScript string.
+    ///
+    /// The UTF-16 code units are copied once into the new string, without
+    /// any UTF-8 conversion. If all the strings but one are empty, that one
+    /// is retained instead of being copied.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let foo = JSString::from("foo");
+    /// let bar = JSString::from("bar");
+    ///
+    /// assert_eq!(JSString::concat(&[&foo, &bar, &foo]), "foobarfoo");
+    /// assert_eq!(JSString::concat(&[]), "");
+    /// ```
+    ///
+    /// Strings can also be concatenated with the `+` and `+=` operators:
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let mut str = JSString::from("foo") + "bar";
+    /// str += &JSString::from("baz");
+    ///
+    /// assert_eq!(str, "foobarbaz");
+    /// ```
+    pub fn concat(strings: &[&JSString]) -> Self {
+        let mut non_empty = strings.iter().filter(|string| !string.is_empty());
+
+        match (non_empty.next(), non_empty.next()) {
+            (None, _) => Self::from_utf16(&[]),
+            (Some(string), None) => (*string).clone(),
+            _ => {
+                let length = strings.iter().map(|string| string.len()).sum();
+                let mut units = Vec::with_capacity(length);
+
+                for string in strings {
+                    units.extend_from_slice(string.as_utf16());
+                }
+
+                Self::from_utf16(&units)
+            }
+        }
+    }
+
+    /// Check whether this JavaScript string starts with `prefix`.
+    ///
+    /// The comparison happens on the UTF-16 code units: only `prefix` is
+    /// converted, and this string is never copied.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("😄 Hello, World!");
+    ///
+    /// assert!(str.starts_with("😄 Hello"));
+    /// assert!(!str.starts_with("Hello"));
+    /// ```
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        let mut units = self.as_utf16().iter();
+
+        prefix
+            .encode_utf16()
+            .all(|unit| units.next() == Some(&unit))
+    }
+
+    /// Check whether `needle` is a substring of this JavaScript string.
+    ///
+    /// The search happens on the UTF-16 code units: only `needle` is
+    /// converted, and this string is never copied.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("Hello, 😄 World!");
+    ///
+    /// assert!(str.contains("😄 World"));
+    /// assert!(!str.contains("world"));
+    /// ```
+    pub fn contains(&self, needle: &str) -> bool {
+        let needle = needle.encode_utf16().collect::<Vec<_>>();
+
+        if needle.is_empty() {
+            return true;
+        }
+
+        self.as_utf16()
+            .windows(needle.len())
+            .any(|window| window == needle.as_slice())
+    }
+
+    /// Convert this JavaScript string into a Rust string.
+    ///
+    /// Any lone surrogate, which isn't valid UTF-16, is replaced by
+    /// [`U+FFFD REPLACEMENT CHARACTER`][std::char::REPLACEMENT_CHARACTER],
+    /// so this never panics, even with untrusted values. This is what
+    /// `to_string()` uses too.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("Hello, 😄!");
+    ///
+    /// assert_eq!(str.to_string_lossy(), "Hello, 😄!");
+    /// ```
+    pub fn to_string_lossy(&self) -> String {
+        String::from_utf16_lossy(self.as_utf16())
+    }
+
+    /// Convert this JavaScript string into UTF-8, and append it to `string`.
+    ///
+    /// Contrary to `to_string()`, which allocates a new `String` each time,
+    /// this reuses the capacity of `string`, which is only grown when needed.
+    /// Clearing and reusing the same `String` converts many JavaScript
+    /// strings with a single allocation.
+    ///
+    /// As with [`JSString::to_string_lossy()`], invalid UTF-16 is replaced by
+    /// `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let mut buffer = String::with_capacity(64);
+    ///
+    /// for str in [JSString::from("Hello"), JSString::from("😄")] {
+    ///     buffer.clear();
+    ///     str.write_to_string(&mut buffer);
+    ///
+    ///     assert_eq!(buffer, str);
+    /// }
+    /// ```
+    pub fn write_to_string(&self, string: &mut String) {
+        string.extend(self.chars_lossy());
+    }
+
+    /// Convert this JavaScript string into UTF-8, and append its bytes to
+    /// `bytes`, without any null terminator.
+    ///
+    /// `bytes` is grown as needed, by at most what the conversion might take
+    /// (see `JSStringGetMaximumUTF8CStringSize`), so a buffer reused across
+    /// calls stops allocating once it's large enough. Each call appends, so
+    /// many strings can be packed into one buffer.
+    ///
+    /// The appended bytes are meant to be UTF-8, but aren't validated: use
+    /// [`JSString::write_to_string()`] to get a `String`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let mut buffer = Vec::new();
+    ///
+    /// JSString::from("foo").write_utf8(&mut buffer);
+    /// JSString::from("😄").write_utf8(&mut buffer);
+    ///
+    /// assert_eq!(buffer, "foo😄".as_bytes());
+    /// ```
+    pub fn write_utf8(&self, bytes: &mut Vec<u8>) {
+        let max_size = unsafe { sys::JSStringGetMaximumUTF8CStringSize(self.raw) };
+        let start = bytes.len();
+        bytes.reserve(max_size);
+
+        unsafe {
+            let actual_size = sys::JSStringGetUTF8CString(
+                self.raw,
+                bytes
+                    .as_mut_ptr()
+                    .add(start)
+                    .cast::<::std::os::raw::c_char>(),
+                max_size,
+            );
+
+            // Subtract 1 to remove the null terminator, if any.
+            bytes.set_len(start + actual_size.saturating_sub(1));
+        }
+    }
+
+    /// Decode the UTF-16 of this JavaScript string, replacing lone
+    /// surrogates by `U+FFFD REPLACEMENT CHARACTER`.
+    fn chars_lossy(&self) -> impl Iterator<Item = char> + '_ {
+        char::decode_utf16(self.as_utf16().iter().copied())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Borrow the UTF-16 code units of this JavaScript string.
+    ///
+    /// The returned slice is backed by the string's internal buffer: nothing
+    /// is copied. It is invalidated when the `JSString` is dropped, which the
+    /// borrow checker enforces.
+    ///
+    /// An empty string yields an empty slice.
+    ///
+    /// ```rust
+    /// # use javascriptcore::JSString;
+    /// let str = JSString::from("a😄");
+    ///
+    /// assert_eq!(str.as_utf16(), &[0x61, 0xd83d, 0xde04]);
+    /// assert_eq!(String::from_utf16(str.as_utf16()).unwrap(), "a😄");
+    ///
+    /// assert!(JSString::from("").as_utf16().is_empty());
+    /// ```
+    pub fn as_utf16(&self) -> &[u16] {
+        let length = self.len();
+        let ptr = unsafe { sys::JSStringGetCharactersPtr(self.raw) };
+
+        if ptr.is_null() || length == 0 {
+            &[]
+        } else {
+            // SAFETY: The backing store lives as long as `self`, and holds
+            // `length` code units.
+            unsafe { slice::from_raw_parts(ptr, length) }
+        }
+    }
+}
+
+/// An iterator over the UTF-16 code units of a [`JSString`].
+///
+/// Get an instance of `Self` with [`JSString::chars`].
+pub struct JSStringChars<'s> {
+    inner: slice::Iter<'s, u16>,
+}
+
+impl Iterator for JSStringChars<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().copied()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl DoubleEndedIterator for JSStringChars<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().copied()
+    }
 }
 
+impl ExactSizeIterator for JSStringChars<'_> {}
+
 impl fmt::Debug for JSString {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "JSString {{ \"{self}\" }}")
@@ -47,38 +368,57 @@ impl fmt::Debug for JSString {
 
 impl fmt::Display for JSString {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        // Optimization: Use stack allocation for small strings to avoid heap allocation
-        const SMALL_STRING_SIZE: usize = 128;
-        
-        unsafe {
-            let max_size = sys::JSStringGetMaximumUTF8CStringSize(self.raw);
-            
-            if max_size <= SMALL_STRING_SIZE {
-                // For small strings, use stack allocation
-                let mut stack_buffer = [0u8; SMALL_STRING_SIZE];
-                let actual_size = sys::JSStringGetUTF8CString(
-                    self.raw,
-                    stack_buffer.as_mut_ptr().cast::<::std::os::raw::c_char>(),
-                    SMALL_STRING_SIZE,
-                );
-                
-                // Create a string slice directly from the stack buffer
-                // Subtract 1 to remove null terminator
-                let s = std::str::from_utf8(&stack_buffer[0..actual_size - 1]).unwrap();
-                write!(fmt, "{s}")
-            } else {
-                // For larger strings, fall back to heap allocation
-                let mut buffer: Vec<u8> = Vec::with_capacity(max_size);
-                let actual_size = sys::JSStringGetUTF8CString(
-                    self.raw,
-                    buffer.as_mut_ptr().cast::<::std::os::raw::c_char>(),
-                    max_size,
-                );
-                buffer.set_len(actual_size - 1);
-                let s = String::from_utf8(buffer).unwrap();
-                write!(fmt, "{s}")
-            }
+        self.chars_lossy()
+            .try_for_each(|c| fmt::Write::write_char(fmt, c))
+    }
+}
+
+impl Clone for JSString {
+    /// Retain the underlying JavaScript string, so that both `JSString`s
+    /// share it and can be dropped independently.
+    fn clone(&self) -> Self {
+        Self {
+            raw: unsafe { sys::JSStringRetain(self.raw) },
+        }
+    }
+}
+
+impl Add<&JSString> for JSString {
+    type Output = JSString;
+
+    fn add(self, other: &JSString) -> JSString {
+        JSString::concat(&[&self, other])
+    }
+}
+
+impl Add<&str> for JSString {
+    type Output = JSString;
+
+    fn add(self, other: &str) -> JSString {
+        if other.is_empty() {
+            return self;
         }
+
+        let units = self
+            .as_utf16()
+            .iter()
+            .copied()
+            .chain(other.encode_utf16())
+            .collect::<Vec<_>>();
+
+        JSString::from_utf16(&units)
+    }
+}
+
+impl AddAssign<&JSString> for JSString {
+    fn add_assign(&mut self, other: &JSString) {
+        *self = JSString::concat(&[self, other]);
+    }
+}
+
+impl AddAssign<&str> for JSString {
+    fn add_assign(&mut self, other: &str) {
+        *self = self.clone() + other;
     }
 }
 
@@ -94,27 +434,45 @@ impl PartialEq for JSString {
     }
 }
 
-fn js_string_equals_str(js_string: &JSString, rust_str: &str) -> bool {
-    // Optimization: Use a stack-allocated buffer for small strings to avoid heap allocation
-    const SMALL_STRING_SIZE: usize = 128;
-    
-    if rust_str.len() < SMALL_STRING_SIZE {
-        // For small strings, use a stack-allocated buffer with a null terminator
-        let mut buffer = [0u8; SMALL_STRING_SIZE + 1]; // +1 for null terminator
-        let bytes = rust_str.as_bytes();
-        buffer[..bytes.len()].copy_from_slice(bytes);
-        buffer[bytes.len()] = 0; // Null terminator
-        
-        unsafe { 
-            sys::JSStringIsEqualToUTF8CString(js_string.raw, buffer.as_ptr() as *const ::std::os::raw::c_char) 
-        }
-    } else {
-        // For larger strings, fall back to CString
-        let utf8 = CString::new(rust_str.as_bytes()).unwrap();
-        unsafe { sys::JSStringIsEqualToUTF8CString(js_string.raw, utf8.as_ptr()) }
+/// Hash the UTF-16 code units, so that strings which are equal according to
+/// `JSStringIsEqual` also have equal hashes.
+impl Hash for JSString {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_utf16().hash(state);
     }
 }
 
+/// Compare the UTF-16 code units lexicographically, like the `<` operator
+/// does on strings in JavaScript.
+///
+/// Note that it means characters outside the Basic Multilingual Plane,
+/// which are encoded as surrogate pairs, sort before the characters in
+/// the range U+E000 to U+FFFF.
+impl Ord for JSString {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_utf16().cmp(other.as_utf16())
+    }
+}
+
+impl PartialOrd for JSString {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+thread_local! {
+    /// The buffer reused by [`js_string_from_str`].
+    static UTF16_BUFFER: RefCell<Vec<u16>> = const { RefCell::new(Vec::new()) };
+}
+
+fn js_string_equals_str(js_string: &JSString, rust_str: &str) -> bool {
+    js_string
+        .as_utf16()
+        .iter()
+        .copied()
+        .eq(rust_str.encode_utf16())
+}
+
 impl<'s> PartialEq<&'s str> for JSString {
     fn eq(&self, other: &&'s str) -> bool {
         js_string_equals_str(self, other)
@@ -139,30 +497,31 @@ impl PartialEq<JSString> for String {
     }
 }
 
-// Helper function to create a JSString from a Rust string
+/// Creates a JavaScript string from `s`, encoded into UTF-16, so characters
+/// like U+0000 are kept.
+///
+/// The UTF-16 code units are written into a thread-local buffer reused
+/// across calls, so no allocation happens once it is large enough.
 fn js_string_from_str(s: &str) -> JSString {
-    // Optimization: Use a stack-allocated buffer for small strings to avoid heap allocation
-    const SMALL_STRING_SIZE: usize = 128;
-    
-    if s.len() < SMALL_STRING_SIZE {
-        // For small strings, use a stack-allocated buffer with a null terminator
-        let mut buffer = [0u8; SMALL_STRING_SIZE + 1]; // +1 for null terminator
-        let bytes = s.as_bytes();
-        buffer[..bytes.len()].copy_from_slice(bytes);
-        buffer[bytes.len()] = 0; // Null terminator
-        
-        JSString {
-            raw: unsafe { 
-                sys::JSStringCreateWithUTF8CString(buffer.as_ptr() as *const ::std::os::raw::c_char) 
-            },
-        }
-    } else {
-        // For larger strings, fall back to CString
-        let c = CString::new(s.as_bytes()).unwrap();
-        JSString {
-            raw: unsafe { sys::JSStringCreateWithUTF8CString(c.as_ptr()) },
+    // Don't keep the memory of an unusually long string forever.
+    const MAX_RETAINED_CAPACITY: usize = 32 * 1024;
+
+    UTF16_BUFFER.with(|buffer| {
+        // `JSString::from_utf16()` only calls JavaScriptCore, which doesn't
+        // call back into Rust, so the buffer is never borrowed twice.
+        let mut buffer = buffer.borrow_mut();
+
+        buffer.clear();
+        buffer.extend(s.encode_utf16());
+
+        let string = JSString::from_utf16(&buffer);
+
+        if buffer.capacity() > MAX_RETAINED_CAPACITY {
+            *buffer = Vec::new();
         }
-    }
+
+        string
+    })
 }
 
 impl From<&str> for JSString {
@@ -177,15 +536,291 @@ impl From<String> for JSString {
     }
 }
 
+/// Collect characters into a JavaScript string, encoding them directly into
+/// UTF-16, without an intermediate `String`.
+///
+/// ```rust
+/// # use javascriptcore::JSString;
+/// let str = ['h', 'i', '😄'].into_iter().collect::<JSString>();
+///
+/// // The emoji is encoded as a surrogate pair.
+/// assert_eq!(str.len(), 4);
+/// assert_eq!(str, "hi😄");
+/// ```
+impl FromIterator<char> for JSString {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self {
+        let mut units = Vec::new();
+        let mut buffer = [0u16; 2];
+
+        for c in iter {
+            units.extend_from_slice(c.encode_utf16(&mut buffer));
+        }
+
+        JSString::from_utf16(&units)
+    }
+}
+
 impl<'s> From<&'s JSString> for String {
     fn from(s: &'s JSString) -> Self {
         s.to_string()
     }
 }
 
+/// Lets a `&JSString` be passed where an `impl Into<Cow<str>>` is expected.
+///
+/// The result is always [owned](Cow::Owned): JavaScriptCore only exposes the
+/// characters of a string as UTF-16, so there is no UTF-8 buffer to borrow
+/// from, even for ASCII strings. To convert a string once and borrow it as
+/// `&str` many times, use a [`JSStringBuf`].
+///
+/// ```rust
+/// # use javascriptcore::JSString;
+/// # use std::borrow::Cow;
+/// fn shout<'a>(s: impl Into<Cow<'a, str>>) -> String {
+///     s.into().to_uppercase()
+/// }
+///
+/// let str = JSString::from("hello");
+///
+/// assert_eq!(shout(&str), "HELLO");
+/// assert_eq!(shout("world"), "WORLD");
+/// ```
+impl<'s> From<&'s JSString> for Cow<'s, str> {
+    fn from(s: &'s JSString) -> Self {
+        Cow::Owned(s.to_string_lossy())
+    }
+}
+
+/// A [`JSString`] along with its UTF-8 conversion.
+///
+/// Each call to `to_string()` on a `JSString` converts the whole string into
+/// UTF-8. A `JSStringBuf` does that conversion once, when it's created, and
+/// then dereferences to `str`, so that it can be passed to any Rust API
+/// expecting a `&str`. JavaScript strings are immutable, so the conversion
+/// never gets out of date.
+///
+/// As with [`JSString::to_string_lossy()`], invalid UTF-16 is replaced by
+/// `U+FFFD REPLACEMENT CHARACTER`.
+///
+/// ```rust
+/// # use javascriptcore::{JSString, JSStringBuf};
+/// let str = JSStringBuf::from(JSString::from("Hello, 😄!"));
+///
+/// assert_eq!(&*str, "Hello, 😄!");
+/// assert!(str.ends_with("😄!"));
+/// assert_eq!(str.js_string().len(), 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct JSStringBuf {
+    js_string: JSString,
+    string: String,
+}
+
+impl JSStringBuf {
+    /// Return the JavaScript string.
+    pub const fn js_string(&self) -> &JSString {
+        &self.js_string
+    }
+
+    /// Return the JavaScript string, dropping its UTF-8 conversion.
+    pub fn into_js_string(self) -> JSString {
+        self.js_string
+    }
+
+    /// Return the UTF-8 conversion, dropping the JavaScript string.
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+impl From<JSString> for JSStringBuf {
+    fn from(js_string: JSString) -> Self {
+        Self {
+            string: js_string.to_string_lossy(),
+            js_string,
+        }
+    }
+}
+
+impl Deref for JSStringBuf {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl AsRef<str> for JSStringBuf {
+    fn as_ref(&self) -> &str {
+        &self.string
+    }
+}
+
+impl fmt::Display for JSStringBuf {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.string, fmt)
+    }
+}
+
+/// A cache of [`JSString`]s, to create each of them only once.
+///
+/// Converting a `&str` into a `JSString` allocates a new JavaScript string
+/// each time, which is wasteful when the same property names are used over
+/// and over, e.g. in a hot loop. Instead, the cache creates the string on the
+/// first call to [`JSStringCache::intern()`], and then returns retained
+/// clones of it.
+///
+/// The cache holds a reference to each of its strings until it's dropped or
+/// [cleared](JSStringCache::clear).
+///
+/// ```rust
+/// # use javascriptcore::{JSContext, JSStringCache, JSValue};
+/// let ctx = JSContext::default();
+/// let object = JSValue::new_from_json(&ctx, r#"{"id": 1}"#).unwrap().as_object().unwrap();
+/// let mut cache = JSStringCache::new();
+///
+/// for _ in 0..10 {
+///     assert!(object.has_property(cache.intern("id")));
+/// }
+///
+/// assert_eq!(cache.len(), 1);
+/// ```
+#[derive(Debug, Default)]
+pub struct JSStringCache {
+    strings: HashMap<String, JSString>,
+}
+
+impl JSStringCache {
+    /// Create an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the JavaScript string for `string`, creating it if it isn't in
+    /// the cache yet.
+    pub fn intern(&mut self, string: &str) -> JSString {
+        if let Some(js_string) = self.strings.get(string) {
+            return js_string.clone();
+        }
+
+        let js_string = JSString::from(string);
+        self.strings.insert(string.to_owned(), js_string.clone());
+
+        js_string
+    }
+
+    /// Return the number of strings in the cache.
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Check whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// Remove all the strings from the cache, releasing them.
+    pub fn clear(&mut self) {
+        self.strings.clear();
+    }
+}
+
+/// An error which can be returned when parsing a number out of a
+/// [`JSString`].
+#[derive(Clone, Debug, Error, PartialEq, Eq)]
+pub enum JSStringParseError {
+    /// The string contains an unpaired surrogate.
+    #[error("string is not valid UTF-16")]
+    InvalidUtf16,
+
+    /// The string is not a valid integer, or is out of range.
+    #[error("invalid integer: {0}")]
+    Int(#[from] ParseIntError),
+
+    /// The string is not a valid float.
+    #[error("invalid float: {0}")]
+    Float(#[from] ParseFloatError),
+}
+
+/// Decode a JavaScript string, and trim it the same way `Number()` does,
+/// before it is parsed.
+fn trimmed_for_parse(s: &JSString) -> Result<String, JSStringParseError> {
+    let s = String::from_utf16(s.as_utf16()).map_err(|_| JSStringParseError::InvalidUtf16)?;
+
+    // JavaScript's whitespaces are Unicode's, except U+0085 (NEXT LINE),
+    // plus U+FEFF (ZERO WIDTH NO-BREAK SPACE).
+    let trimmed = s.trim_matches(|c: char| (c.is_whitespace() && c != '\u{85}') || c == '\u{feff}');
+
+    Ok(trimmed.to_owned())
+}
+
+/// Parse a decimal integer out of a `JSString`.
+///
+/// Leading and trailing whitespaces are trimmed, like JavaScript's
+/// `Number()` does, then the rest of the string is parsed with Rust's
+/// [`str::parse`]. Values out of range are errors, they never wrap.
+///
+/// ```rust
+/// # use javascriptcore::JSString;
+/// assert_eq!(i64::try_from(&JSString::from(" -42\n")), Ok(-42));
+/// assert!(i64::try_from(&JSString::from("9223372036854775808")).is_err());
+/// ```
+impl TryFrom<&JSString> for i64 {
+    type Error = JSStringParseError;
+
+    fn try_from(s: &JSString) -> Result<Self, Self::Error> {
+        Ok(trimmed_for_parse(s)?.parse()?)
+    }
+}
+
+/// Parse a decimal unsigned integer out of a `JSString`.
+///
+/// Leading and trailing whitespaces are trimmed, like JavaScript's
+/// `Number()` does, then the rest of the string is parsed with Rust's
+/// [`str::parse`]. Values out of range (including negative values) are
+/// errors, they never wrap.
+///
+/// ```rust
+/// # use javascriptcore::JSString;
+/// assert_eq!(u64::try_from(&JSString::from("18446744073709551615")), Ok(u64::MAX));
+/// assert!(u64::try_from(&JSString::from("-1")).is_err());
+/// ```
+impl TryFrom<&JSString> for u64 {
+    type Error = JSStringParseError;
+
+    fn try_from(s: &JSString) -> Result<Self, Self::Error> {
+        Ok(trimmed_for_parse(s)?.parse()?)
+    }
+}
+
+/// Parse a float out of a `JSString`.
+///
+/// Leading and trailing whitespaces are trimmed, like JavaScript's
+/// `Number()` does, then the rest of the string is parsed with Rust's
+/// [`str::parse`]. Hence, the accepted syntax is Rust's one and not
+/// JavaScript's one: an empty string is an error and not `0`, `"inf"`
+/// is accepted in addition to `"Infinity"`, and hexadecimal notations
+/// like `"0x10"` aren't supported.
+///
+/// ```rust
+/// # use javascriptcore::JSString;
+/// assert_eq!(f64::try_from(&JSString::from("\t1.5e3 ")), Ok(1500.));
+/// assert!(f64::try_from(&JSString::from("")).is_err());
+/// ```
+impl TryFrom<&JSString> for f64 {
+    type Error = JSStringParseError;
+
+    fn try_from(s: &JSString) -> Result<Self, Self::Error> {
+        Ok(trimmed_for_parse(s)?.parse()?)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::JSString;
+    use super::{JSStringBuf, JSStringCache, JSStringParseError};
+    use crate::{sys, JSString};
+    use std::borrow::Cow;
+    use std::ptr;
 
     #[test]
     fn from_conversion() {
@@ -217,6 +852,209 @@ mod tests {
         assert_eq!(s, a);
     }
 
+    #[test]
+    fn long_strings() {
+        let long = "é".repeat(250) + &"a".repeat(250);
+        assert_eq!(long.chars().count(), 500);
+
+        let a = JSString::from(long.as_str());
+        assert_eq!(a.len(), 500);
+        assert_eq!(a.to_string(), long);
+        assert_eq!(a, long);
+        assert_ne!(a, &long[..long.len() - 1]);
+
+        // The buffer is reused, so a shorter string mustn't see the end of
+        // a longer one.
+        let b = JSString::from("éa");
+        assert_eq!(b.len(), 2);
+        assert_eq!(b, "éa");
+        assert_eq!(a, long);
+
+        let huge = "x".repeat(100_000);
+        assert_eq!(JSString::from(huge.as_str()).len(), 100_000);
+        assert_eq!(JSString::from("y"), "y");
+    }
+
+    #[test]
+    fn interior_nul() {
+        let a = JSString::from("a\0b");
+        assert_eq!(a.len(), 3);
+        assert_eq!(a.as_utf16(), &[0x61, 0, 0x62]);
+        assert_eq!(a.to_string(), "a\0b");
+        assert_eq!(a, "a\0b");
+        assert_ne!(a, "a");
+
+        let b = JSString::from(String::from("\0"));
+        assert_eq!(b.len(), 1);
+        assert_eq!(b, "\0");
+        assert_ne!(b, "");
+    }
+
+    #[test]
+    fn equality_with_interior_nul() {
+        let a = JSString::from("a\0b");
+        let prefix = JSString::from("a");
+
+        // Neither string is the NUL-truncated prefix of the other.
+        assert_ne!(a, "a");
+        assert_ne!("a", a);
+        assert_ne!(a, String::from("a"));
+        assert_ne!(prefix, "a\0b");
+        assert_ne!(prefix, "a\0");
+        assert_ne!(a, prefix);
+
+        assert_eq!(a, "a\0b");
+        assert_eq!(String::from("a\0b"), a);
+        assert_eq!(a, JSString::from_utf16(&[0x61, 0, 0x62]));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(JSString::from("").to_string(), "");
+        assert_eq!(format!("{}", JSString::from("")), "");
+        assert_eq!(JSString::from("abc").to_string(), "abc");
+
+        // Large enough to not be formatted on the stack.
+        let long = "😄".repeat(100);
+        assert_eq!(JSString::from(long.as_str()).to_string(), long);
+    }
+
+    #[test]
+    fn to_string_lossy() {
+        assert_eq!(JSString::from("").to_string_lossy(), "");
+        assert_eq!(JSString::from("∀𝑥∈ℝ,𝑥²≥0").to_string_lossy(), "∀𝑥∈ℝ,𝑥²≥0");
+
+        // A lone surrogate has no UTF-8 representation.
+        let a = JSString::from_utf16(&[0x61, 0xd83d, 0x62]);
+        assert_eq!(a.to_string_lossy(), "a\u{FFFD}b");
+        assert_eq!(a.to_string(), "a\u{FFFD}b");
+        assert_eq!(format!("{a:?}"), "JSString { \"a\u{FFFD}b\" }");
+    }
+
+    #[test]
+    fn clone() {
+        let a: JSString = "∀𝑥∈ℝ,𝑥²≥0".into();
+        let b = a.clone();
+        assert_eq!(a, b);
+
+        drop(a);
+
+        assert_eq!(b.len(), 11);
+        assert_eq!(b.to_string(), "∀𝑥∈ℝ,𝑥²≥0");
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::{hash_map::DefaultHasher, HashMap};
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(s: &JSString) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            s.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: JSString = "abc".into();
+        let b: JSString = "abc".to_owned().into();
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        let c: JSString = "😄".into();
+        let d = JSString::from("😄");
+        assert_eq!(hash_of(&c), hash_of(&d));
+
+        let mut map = HashMap::new();
+        map.insert(a, 1);
+        map.insert(c, 2);
+
+        assert_eq!(map.get(&b), Some(&1));
+        assert_eq!(map.get(&d), Some(&2));
+        assert_eq!(map.get(&JSString::from("abd")), None);
+    }
+
+    #[test]
+    fn try_into_numbers() {
+        assert_eq!(i64::try_from(&JSString::from("42")), Ok(42));
+        assert_eq!(i64::try_from(&JSString::from(" \u{feff}-42\n\t")), Ok(-42));
+        assert_eq!(i64::try_from(&JSString::from("\u{2028}42\u{3000}")), Ok(42));
+        // `Number("\u{85}42")` is `NaN`.
+        assert!(i64::try_from(&JSString::from("\u{85}42")).is_err());
+        assert!(f64::try_from(&JSString::from("4.2\u{85}")).is_err());
+        assert_eq!(
+            i64::try_from(&JSString::from("-9223372036854775808")),
+            Ok(i64::MIN)
+        );
+        assert!(matches!(
+            i64::try_from(&JSString::from("9223372036854775808")),
+            Err(JSStringParseError::Int(_))
+        ));
+        assert!(i64::try_from(&JSString::from("4 2")).is_err());
+        assert!(i64::try_from(&JSString::from("4.2")).is_err());
+
+        assert_eq!(u64::try_from(&JSString::from(" 42 ")), Ok(42));
+        assert_eq!(
+            u64::try_from(&JSString::from("18446744073709551615")),
+            Ok(u64::MAX)
+        );
+        assert!(u64::try_from(&JSString::from("18446744073709551616")).is_err());
+        assert!(u64::try_from(&JSString::from("-1")).is_err());
+
+        assert_eq!(f64::try_from(&JSString::from(" 4.2 ")), Ok(4.2));
+        assert_eq!(f64::try_from(&JSString::from("-1e3")), Ok(-1000.));
+        assert!(matches!(
+            f64::try_from(&JSString::from("abc")),
+            Err(JSStringParseError::Float(_))
+        ));
+
+        assert_eq!(
+            i64::try_from(&JSString::from_utf16(&[0x31, 0xd83d])),
+            Err(JSStringParseError::InvalidUtf16)
+        );
+    }
+
+    #[test]
+    fn from_utf16() {
+        let a = JSString::from_utf16(&[0x61, 0x62, 0x63]);
+        assert_eq!(a, "abc");
+
+        // A lone surrogate can't be represented in UTF-8, but it's
+        // preserved by a UTF-16 round-trip.
+        let units = [0x61, 0xd83d, 0x62];
+        let b = JSString::from_utf16(&units);
+        assert_eq!(b.len(), units.len());
+        assert_eq!(b.as_utf16(), &units);
+
+        let c = JSString::from_utf16(&[]);
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn ordering() {
+        use std::cmp::Ordering;
+        use std::collections::BTreeSet;
+
+        let a: JSString = "abc".into();
+        let b: JSString = "abd".into();
+        let c: JSString = "ab".into();
+        assert!(a < b);
+        assert!(c < a);
+        assert_eq!(a.cmp(&JSString::from("abc")), Ordering::Equal);
+
+        // Empty strings sort first.
+        let empty: JSString = "".into();
+        assert!(empty < c);
+        assert_eq!(empty.cmp(&JSString::from("")), Ordering::Equal);
+
+        // Surrogate pairs compare by code unit, like in JavaScript.
+        let emoji: JSString = "😄".into();
+        let private_use: JSString = "\u{e000}".into();
+        assert!(emoji < private_use);
+        assert!(a < emoji);
+
+        let set = [b, emoji, a, empty, c].into_iter().collect::<BTreeSet<_>>();
+        let sorted = set.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(sorted, &["", "ab", "abc", "abd", "😄"]);
+    }
+
     #[test]
     fn len() {
         let a: JSString = "😄".into();
@@ -235,4 +1073,245 @@ mod tests {
         assert!(JSString::from("").is_empty());
         assert!(!JSString::from("abc").is_empty());
     }
+
+    #[test]
+    fn chars() {
+        let a: JSString = "abc".into();
+        assert_eq!(a.chars().collect::<Vec<_>>(), &[0x61, 0x62, 0x63]);
+        assert_eq!(a.chars().rev().collect::<Vec<_>>(), &[0x63, 0x62, 0x61]);
+        assert_eq!(a.chars().len(), 3);
+
+        let b: JSString = "😄".into();
+        assert_eq!(b.chars().collect::<Vec<_>>(), &[0xd83d, 0xde04]);
+
+        let c: JSString = "".into();
+        assert_eq!(c.chars().next(), None);
+    }
+
+    #[test]
+    fn as_utf16() {
+        let a: JSString = "abc".into();
+        assert_eq!(a.as_utf16(), &[0x61, 0x62, 0x63]);
+
+        let b: JSString = "😄".into();
+        assert_eq!(b.as_utf16(), &[0xd83d, 0xde04]);
+        assert_eq!(b.as_utf16().len(), b.len());
+
+        let c: JSString = "".into();
+        assert!(c.as_utf16().is_empty());
+    }
+
+    #[test]
+    fn starts_with() {
+        let a: JSString = "∀𝑥∈ℝ,𝑥²≥0".into();
+        assert!(a.starts_with(""));
+        assert!(a.starts_with("∀"));
+        assert!(a.starts_with("∀𝑥∈"));
+        assert!(a.starts_with("∀𝑥∈ℝ,𝑥²≥0"));
+        assert!(!a.starts_with("∀𝑥∈ℝ,𝑥²≥0 "));
+        assert!(!a.starts_with("𝑥"));
+
+        let b: JSString = "".into();
+        assert!(b.starts_with(""));
+        assert!(!b.starts_with("a"));
+    }
+
+    #[test]
+    fn contains() {
+        let a: JSString = "∀𝑥∈ℝ,𝑥²≥0".into();
+        assert!(a.contains(""));
+        assert!(a.contains("𝑥²"));
+        assert!(a.contains("ℝ,𝑥"));
+        assert!(a.contains("≥0"));
+        assert!(!a.contains("𝑦"));
+        assert!(!a.contains("∀𝑥∈ℝ,𝑥²≥0 "));
+
+        let b: JSString = "".into();
+        assert!(b.contains(""));
+        assert!(!b.contains("a"));
+    }
+
+    #[test]
+    fn code_units() {
+        let a: JSString = "∀𝑥".into();
+        assert_eq!(a.code_units(), &[0x2200, 0xd835, 0xdc65]);
+        assert!(JSString::from("").code_units().is_empty());
+    }
+
+    #[test]
+    fn string_cache() {
+        let mut cache = JSStringCache::new();
+        assert!(cache.is_empty());
+
+        let first = cache.intern("foo");
+        assert_eq!(first, "foo");
+
+        for _ in 0..1000 {
+            let foo = cache.intern("foo");
+            assert_eq!(foo, first);
+            assert_eq!(foo.raw, first.raw);
+        }
+
+        let bar = cache.intern("bar");
+        assert_eq!(bar, "bar");
+        assert_ne!(bar, first);
+        assert_eq!(cache.len(), 2);
+
+        // Interned strings outlive the cache.
+        cache.clear();
+        assert!(cache.is_empty());
+        drop(cache);
+        assert_eq!(first, "foo");
+    }
+
+    #[test]
+    fn from_chars() {
+        let str: JSString = ['h', 'i', '😀'].into_iter().collect();
+        assert_eq!(str, "hi😀");
+        assert_eq!(str.len(), 4);
+
+        let str: JSString = ['h', '😀'].into_iter().collect();
+        assert_eq!(str.code_units(), &[0x68, 0xd83d, 0xde00]);
+        assert_eq!(str.len(), 3);
+
+        let str: JSString = "∀𝑥".chars().rev().collect();
+        assert_eq!(str, "𝑥∀");
+
+        assert!(std::iter::empty::<char>().collect::<JSString>().is_empty());
+    }
+
+    #[test]
+    fn write_to_string() {
+        let strings = ["Hello", "", "∀𝑥 ∈ ℝ", "😄!"].map(JSString::from);
+        let mut buffer = String::from("> ");
+
+        strings[0].write_to_string(&mut buffer);
+        assert_eq!(buffer, "> Hello");
+
+        // Converting into a reused buffer doesn't reallocate.
+        buffer.reserve(64);
+        let capacity = buffer.capacity();
+        let ptr = buffer.as_ptr();
+
+        for str in &strings {
+            buffer.clear();
+            str.write_to_string(&mut buffer);
+
+            assert_eq!(buffer, *str);
+            assert_eq!(buffer.capacity(), capacity);
+            assert_eq!(buffer.as_ptr(), ptr);
+        }
+
+        // The buffer grows as needed.
+        let long = JSString::from("a".repeat(1000));
+        long.write_to_string(&mut buffer);
+        assert_eq!(buffer.len(), "😄!".len() + 1000);
+
+        buffer.clear();
+        JSString::from_utf16(&[0x61, 0xd83d, 0x62]).write_to_string(&mut buffer);
+        assert_eq!(buffer, "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn write_utf8() {
+        let strings = ["Hello", "", "∀𝑥 ∈ ℝ", "😄!"];
+        let mut buffer = Vec::new();
+        let mut ranges = Vec::new();
+
+        for str in strings {
+            let start = buffer.len();
+            JSString::from(str).write_utf8(&mut buffer);
+            ranges.push(start..buffer.len());
+        }
+
+        for (str, range) in strings.iter().zip(ranges) {
+            assert_eq!(&buffer[range], str.as_bytes());
+        }
+
+        // Reusing the buffer doesn't reallocate.
+        let capacity = buffer.capacity();
+        for str in strings {
+            buffer.clear();
+            JSString::from(str).write_utf8(&mut buffer);
+            assert_eq!(buffer, str.as_bytes());
+            assert_eq!(buffer.capacity(), capacity);
+        }
+    }
+
+    #[test]
+    fn into_cow() {
+        let ascii = JSString::from("hello");
+        let emoji = JSString::from("😄!");
+
+        assert_eq!(Cow::from(&ascii), "hello");
+        assert_eq!(Cow::from(&emoji), "😄!");
+        assert!(matches!(Cow::from(&ascii), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn string_buf() {
+        let js_string = JSString::from("∀𝑥 ∈ ℝ");
+        let str = JSStringBuf::from(js_string.clone());
+
+        let slice: &str = &str;
+        assert_eq!(slice, "∀𝑥 ∈ ℝ");
+        assert_eq!(str.chars().count(), 5);
+        assert_eq!(str.as_ref(), "∀𝑥 ∈ ℝ");
+        assert_eq!(str.to_string(), "∀𝑥 ∈ ℝ");
+        assert_eq!(format!("{str:>8}"), "   ∀𝑥 ∈ ℝ");
+        assert_eq!(*str.js_string(), js_string);
+        assert_eq!(str.clone().into_js_string(), js_string);
+        assert_eq!(str.into_string(), "∀𝑥 ∈ ℝ");
+
+        let str = JSStringBuf::from(JSString::from_utf16(&[0x61, 0xd83d]));
+        assert_eq!(&*str, "a\u{fffd}");
+    }
+
+    #[test]
+    fn from_raw() {
+        assert!(unsafe { JSString::from_raw(ptr::null_mut()) }.is_none());
+
+        let a = JSString::from("abc");
+        let raw = unsafe { sys::JSStringRetain(a.raw) };
+        let b = unsafe { JSString::from_raw(raw) }.unwrap();
+        assert_eq!(b, "abc");
+
+        // Both can be dropped independently.
+        drop(a);
+        assert_eq!(b, "abc");
+    }
+
+    #[test]
+    fn concat() {
+        let foo: JSString = "foo".into();
+        let bar: JSString = "bar".into();
+        let empty: JSString = "".into();
+
+        assert_eq!(foo.clone() + &bar, "foobar");
+        assert_eq!(foo.clone() + "bar", "foobar");
+        assert_eq!(foo.clone() + "😄", "foo😄");
+        assert_eq!(JSString::concat(&[&foo, &empty, &bar, &foo]), "foobarfoo");
+        assert!(JSString::concat(&[]).is_empty());
+        assert!(JSString::concat(&[&empty, &empty]).is_empty());
+
+        // Concatenating with an empty string shares the other one.
+        let sum = JSString::concat(&[&empty, &foo]);
+        assert_eq!(sum.raw, foo.raw);
+        let sum = foo.clone() + &empty;
+        assert_eq!(sum.raw, foo.raw);
+        let sum = empty.clone() + &foo;
+        assert_eq!(sum.raw, foo.raw);
+
+        let mut str = JSString::from("a");
+        str += &bar;
+        str += "baz";
+        str += "";
+        assert_eq!(str, "abarbaz");
+        assert_eq!(str.len(), 7);
+
+        // Unpaired surrogates are kept as is.
+        let high = JSString::from_utf16(&[0xd83d]);
+        let low = JSString::from_utf16(&[0xde04]);
+        assert_eq!(high + &low, "😄");
+    }
 }
@@ -7,6 +7,43 @@
 use crate::{sys, JSException, JSObject, JSTypedArray, JSTypedArrayType, JSValue};
 use std::{ptr, slice};
 
+/// A Rust type that can be an element of a Typed Array.
+///
+/// It maps a Rust type to its [`JSTypedArrayType`], e.g. `f64` to
+/// `Float64Array`. See [`JSValue::new_typed_array_with_vec()`].
+///
+/// # Safety
+///
+/// The implementing type must have the exact size and layout of an element
+/// of [`Self::TYPE`].
+pub unsafe trait JSTypedArrayElement: Copy + 'static {
+    /// The type of Typed Array holding elements of `Self`.
+    const TYPE: JSTypedArrayType;
+}
+
+macro_rules! typed_array_element {
+    ($($ty:ty => $array_ty:ident),* $(,)?) => {
+        $(
+            unsafe impl JSTypedArrayElement for $ty {
+                const TYPE: JSTypedArrayType = JSTypedArrayType::$array_ty;
+            }
+        )*
+    };
+}
+
+typed_array_element!(
+    i8 => Int8Array,
+    i16 => Int16Array,
+    i32 => Int32Array,
+    i64 => BigInt64Array,
+    u8 => Uint8Array,
+    u16 => Uint16Array,
+    u32 => Uint32Array,
+    u64 => BigUint64Array,
+    f32 => Float32Array,
+    f64 => Float64Array,
+);
+
 impl JSTypedArray {
     /// Create a new [`Self`] from its raw pointer directly.
     ///
@@ -172,7 +209,7 @@ impl JSTypedArray {
 
     unsafe fn as_mut_slice_impl(&self) -> Result<&mut [u8], JSException> {
         let offset = self.byte_offset()?;
-        let length = self.len()?;
+        let length = self.byte_length()?;
 
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let ptr = unsafe { sys::JSObjectGetTypedArrayBytesPtr(self.ctx, self.raw, &mut exception) };
@@ -247,6 +284,75 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn new_zeroed() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let array = JSValue::new_typed_array(&ctx, JSTypedArrayType::Int16Array, 4)?;
+
+        assert!(array.is_typed_array());
+
+        let array = array.as_typed_array()?;
+        assert_eq!(array.ty()?, JSTypedArrayType::Int16Array);
+        assert_eq!(array.len()?, 4);
+        assert_eq!(array.to_vec()?, &[0; 8]);
+
+        assert!(JSValue::new_typed_array(&ctx, JSTypedArrayType::None, 4).is_err());
+        assert!(JSValue::new_typed_array(&ctx, JSTypedArrayType::ArrayBuffer, 4).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_float64_array_with_vec() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let elements = vec![1.5f64, -2.25, 1e300];
+        let array_as_value = JSValue::new_typed_array_with_vec(&ctx, elements.clone())?;
+        let array = array_as_value.as_typed_array()?;
+
+        assert_eq!(array.ty()?, JSTypedArrayType::Float64Array);
+        assert_eq!(array.len()?, 3);
+        assert_eq!(array.byte_length()?, 24);
+
+        let read = array
+            .to_vec()?
+            .chunks_exact(8)
+            .map(|chunk| f64::from_ne_bytes(chunk.try_into().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(read, elements);
+
+        ctx.global_object()?.set_property("array", array_as_value)?;
+        let sum = evaluate_script(&ctx, "array[0] + array[1]", None, "foo.js", 1)?;
+        assert_eq!(sum.as_number()?, -0.75);
+
+        Ok(())
+    }
+
+    #[test]
+    fn new_with_vec_types() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        macro_rules! check {
+            ($elements:expr, $ty:ident) => {
+                let array = JSValue::new_typed_array_with_vec(&ctx, $elements)?.as_typed_array()?;
+                assert_eq!(array.ty()?, JSTypedArrayType::$ty);
+                assert_eq!(array.len()?, 2);
+            };
+        }
+
+        check!(vec![1i8, 2], Int8Array);
+        check!(vec![1i16, 2], Int16Array);
+        check!(vec![1i32, 2], Int32Array);
+        check!(vec![1i64, 2], BigInt64Array);
+        check!(vec![1u8, 2], Uint8Array);
+        check!(vec![1u16, 2], Uint16Array);
+        check!(vec![1u32, 2], Uint32Array);
+        check!(vec![1u64, 2], BigUint64Array);
+        check!(vec![1f32, 2.], Float32Array);
+        check!(vec![1f64, 2.], Float64Array);
+
+        Ok(())
+    }
+
     #[test]
     fn len() -> Result<(), JSException> {
         let ctx = JSContext::default();
@@ -7,21 +7,52 @@
 use sys::JSObjectCallAsFunctionCallback;
 
 use crate::{
-    sys, JSClass, JSContext, JSException, JSObject, JSString, JSType, JSTypedArray,
-    JSTypedArrayType, JSValue,
+    class, sys, FromJSValue, JSClass, JSContext, JSException, JSMap, JSObject, JSSet, JSString,
+    JSType, JSTypedArray, JSTypedArrayElement, JSTypedArrayType, JSValue,
 };
-use std::ptr;
+use std::{fmt, ptr};
 
 impl JSValue {
     /// Create a new [`Self`] from its raw pointer directly.
     ///
+    /// It can be used to receive values from other code using
+    /// JavaScriptCore directly, see [`JSValue::as_raw()`].
+    ///
     /// # Safety
     ///
-    /// Ensure `raw` is valid.
+    /// Ensure `raw` is valid, and is a value of the context `ctx`, which must
+    /// outlive the returned value.
+    ///
+    /// Values aren't reference counted: nothing is retained, nor released
+    /// when the returned value is dropped. The garbage collector only keeps
+    /// `raw` alive while it's on the stack, or reachable from JavaScript,
+    /// unless it's [protected](JSValue::protect).
     pub const unsafe fn from_raw(ctx: sys::JSContextRef, raw: sys::JSValueRef) -> Self {
         Self { raw, ctx }
     }
 
+    /// Returns the raw pointer of this value, e.g. to pass it to other code
+    /// using JavaScriptCore directly.
+    ///
+    /// The pointer is valid as long as the value is alive, see the safety
+    /// section of [`JSValue::from_raw()`]. Its context is the one of this
+    /// value.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{sys, JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let value = JSValue::new_number(&ctx, 42.);
+    ///
+    /// let raw: sys::JSValueRef = value.as_raw();
+    /// assert!(unsafe { sys::JSValueIsNumber(ctx.as_raw(), raw) });
+    ///
+    /// let value = unsafe { JSValue::from_raw(ctx.as_raw(), raw) };
+    /// assert_eq!(value.as_number().unwrap(), 42.);
+    /// ```
+    pub const fn as_raw(&self) -> sys::JSValueRef {
+        self.raw
+    }
+
     /// Creates a JavaScript value of the `undefined` type.
     ///
     /// * `ctx`: The execution context to use.
@@ -94,6 +125,10 @@ impl JSValue {
     ///
     /// Returns a `JSValue` of the `number` type, representing the value of `number`.
     ///
+    /// JavaScript numbers are IEEE 754 doubles, exactly like `f64`, so the
+    /// value round-trips unchanged. Integers beyond 2<sup>53</sup> are thus
+    /// subject to the same loss of precision as in Rust.
+    ///
     /// ```
     /// # use javascriptcore::*;
     /// let ctx = JSContext::default();
@@ -156,12 +191,17 @@ impl JSValue {
     ///
     /// Returns a `JSValue` of the `symbol` type, whose description matches the one provided.
     ///
+    /// The description is only informative, it doesn't identify the symbol:
+    /// each call creates a new symbol, different from all others, like
+    /// `Symbol(description)` in JavaScript.
+    ///
     /// ```
     /// # use javascriptcore::*;
     /// let ctx = JSContext::default();
     ///
     /// let v = JSValue::new_symbol(&ctx, "abc");
     /// assert!(v.is_symbol());
+    /// assert_ne!(v, JSValue::new_symbol(&ctx, "abc"));
     /// ```
     ///
     /// # See also
@@ -176,6 +216,71 @@ impl JSValue {
         }
     }
 
+    /// Creates a JavaScript value of the `bigint` type, holding an integer.
+    ///
+    /// Contrary to [`JSValue::new_number()`], there is no loss of precision
+    /// for integers beyond 2<sup>53</sup>.
+    ///
+    /// The C API of JavaScriptCore has no way to create `BigInt`s, except in
+    /// its most recent versions, so this calls the `BigInt` function of the
+    /// global object. Returns an [exception](JSException) if it has been
+    /// replaced by something which isn't a function.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let v = JSValue::new_big_int_from_i64(&ctx, i64::MAX).unwrap();
+    /// assert!(v.is_big_int());
+    /// assert_eq!(v.as_i64(), Some(i64::MAX));
+    /// assert_eq!(v.as_string().unwrap(), "9223372036854775807");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_i64()`]
+    /// - [`JSValue::is_big_int()`]
+    /// - [`JSValue::new_big_int_from_string()`]
+    pub fn new_big_int_from_i64(ctx: &JSContext, integer: i64) -> Result<Self, JSException> {
+        Self::new_big_int_from_string(ctx, integer.to_string())
+    }
+
+    /// Creates a JavaScript value of the `bigint` type, from the string
+    /// representation of an integer, like `BigInt(string)` in JavaScript.
+    ///
+    /// The integer can be arbitrarily large, and written in decimal, or in
+    /// hexadecimal, octal or binary with the `0x`, `0o` or `0b` prefixes.
+    /// Returns an [exception](JSException) if the string isn't a valid
+    /// integer. See [`JSValue::new_big_int_from_i64()`] for the caveats.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let v = JSValue::new_big_int_from_string(&ctx, "123456789012345678901234567890").unwrap();
+    /// assert!(v.is_big_int());
+    /// assert_eq!(v.as_i64(), None);
+    /// assert_eq!(v.as_string().unwrap(), "123456789012345678901234567890");
+    ///
+    /// assert!(JSValue::new_big_int_from_string(&ctx, "1.5").is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_string()`]
+    /// - [`JSValue::is_big_int()`]
+    pub fn new_big_int_from_string<S: Into<JSString>>(
+        ctx: &JSContext,
+        string: S,
+    ) -> Result<Self, JSException> {
+        let big_int = ctx
+            .global_object()?
+            .try_get_property("BigInt")?
+            .as_object()?;
+
+        big_int.call_as_function(None, &[JSValue::new_string(ctx, string)])
+    }
+
     /// Creates a JavaScript value of the `array` type.
     ///
     /// * `ctx`: The execution context to use.
@@ -320,6 +425,194 @@ impl JSValue {
         Ok(unsafe { Self::from_raw(ctx.raw, result) })
     }
 
+    /// Creates a JavaScript value of the `TypedArray` type, with all elements
+    /// set to zero.
+    ///
+    /// * `ctx`: The execution context to use.
+    /// * `ty`: The type of the typed array, e.g. `Float64Array`.
+    /// * `length`: The number of elements, not the number of bytes.
+    ///
+    /// Returns a `JSValue` of the `TypedArray` type, otherwise an
+    /// [exception](JSException), for example if `ty` is
+    /// [`JSTypedArrayType::None`] or [`JSTypedArrayType::ArrayBuffer`].
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSTypedArrayType, JSValue};
+    /// let ctx = JSContext::default();
+    /// let array = JSValue::new_typed_array(&ctx, JSTypedArrayType::Float64Array, 3)
+    ///     .unwrap()
+    ///     .as_typed_array()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(array.len().unwrap(), 3);
+    /// assert_eq!(array.byte_length().unwrap(), 24);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_typed_array_with_vec()`]
+    pub fn new_typed_array(
+        ctx: &JSContext,
+        ty: JSTypedArrayType,
+        length: usize,
+    ) -> Result<Self, JSException> {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let result = unsafe { sys::JSObjectMakeTypedArray(ctx.raw, ty, length, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(ctx.raw, exception).into() });
+        }
+
+        if result.is_null() {
+            return Err(Self::new_string(ctx, "Failed to make a new typed array").into());
+        }
+
+        Ok(unsafe { Self::from_raw(ctx.raw, result) })
+    }
+
+    /// Creates a JavaScript value of the `TypedArray` type, backed by the
+    /// elements of a `Vec` without copying them.
+    ///
+    /// * `ctx`: The execution context to use.
+    /// * `elements`: The typed array elements. Their type determines the type
+    ///   of the typed array, see [`JSTypedArrayElement`].
+    ///
+    /// JavaScript takes ownership of `elements`: the `Vec` is dropped when the
+    /// typed array, and all the values sharing its buffer, are garbage
+    /// collected. It may thus outlive the `JSValue` returned here, and even the
+    /// context.
+    ///
+    /// Returns a `JSValue` of the `TypedArray` type, otherwise an
+    /// [exception](JSException). The `Vec` is dropped in case of an error.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{JSContext, JSTypedArrayType, JSValue};
+    /// let ctx = JSContext::default();
+    /// let array = JSValue::new_typed_array_with_vec(&ctx, vec![1.5f64, 2.5, 3.5])
+    ///     .unwrap()
+    ///     .as_typed_array()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(array.ty().unwrap(), JSTypedArrayType::Float64Array);
+    /// assert_eq!(array.len().unwrap(), 3);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_typed_array()`]
+    /// - [`JSValue::new_typed_array_with_bytes()`]
+    pub fn new_typed_array_with_vec<T>(
+        ctx: &JSContext,
+        elements: Vec<T>,
+    ) -> Result<Self, JSException>
+    where
+        T: JSTypedArrayElement,
+    {
+        unsafe extern "C" fn deallocate<T>(
+            _bytes: *mut std::os::raw::c_void,
+            deallocator_ctx: *mut std::os::raw::c_void,
+        ) {
+            drop(unsafe { Box::from_raw(deallocator_ctx.cast::<Vec<T>>()) });
+        }
+
+        let mut elements = Box::new(elements);
+        let bytes = elements.as_mut_ptr();
+        let byte_length = size_of_val(elements.as_slice());
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let result = unsafe {
+            sys::JSObjectMakeTypedArrayWithBytesNoCopy(
+                ctx.raw,
+                T::TYPE,
+                bytes.cast(),
+                byte_length,
+                Some(deallocate::<T>),
+                Box::into_raw(elements).cast(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(ctx.raw, exception).into() });
+        }
+
+        if result.is_null() {
+            return Err(Self::new_string(ctx, "Failed to make a new typed array").into());
+        }
+
+        Ok(unsafe { Self::from_raw(ctx.raw, result) })
+    }
+
+    /// Creates a JavaScript value of the `ArrayBuffer` type, backed by some
+    /// bytes without copying them.
+    ///
+    /// * `ctx`: The execution context to use.
+    /// * `bytes`: The owner of the bytes, typically a `Vec<u8>` or a
+    ///   `Box<[u8]>`.
+    ///
+    /// JavaScript takes ownership of `bytes`: it is dropped when the
+    /// `ArrayBuffer`, and all the values sharing it (like typed arrays), are
+    /// garbage collected, or at the latest when the context group is
+    /// destroyed. It may thus outlive the `JSValue` returned here.
+    ///
+    /// Returns a `JSValue` of the `ArrayBuffer` type, otherwise an
+    /// [exception](JSException). `bytes` is dropped in case of an error.
+    ///
+    /// ```rust
+    /// # use javascriptcore::{evaluate_script, JSContext, JSValue};
+    /// let ctx = JSContext::default();
+    /// let buffer = JSValue::new_array_buffer(&ctx, vec![1u8, 2, 3]).unwrap();
+    /// assert!(buffer.is_array_buffer());
+    ///
+    /// ctx.global_object().unwrap().set_property("buffer", buffer).unwrap();
+    /// let last = evaluate_script(&ctx, "new Uint8Array(buffer)[2]", None, "foo.js", 1).unwrap();
+    /// assert_eq!(last.as_number().unwrap(), 3.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::array_buffer_bytes()`]
+    /// - [`JSValue::is_array_buffer()`]
+    pub fn new_array_buffer<B>(ctx: &JSContext, bytes: B) -> Result<Self, JSException>
+    where
+        B: AsMut<[u8]> + 'static,
+    {
+        unsafe extern "C" fn deallocate<B>(
+            _bytes: *mut std::os::raw::c_void,
+            deallocator_ctx: *mut std::os::raw::c_void,
+        ) {
+            // `bytes` may own values, e.g. a `Protected`, which can't call
+            // JavaScriptCore from here.
+            class::finalizing(|| drop(unsafe { Box::from_raw(deallocator_ctx.cast::<B>()) }));
+        }
+
+        let mut bytes = Box::new(bytes);
+        let slice = (*bytes).as_mut();
+        let (pointer, byte_length) = (slice.as_mut_ptr(), slice.len());
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        let result = unsafe {
+            sys::JSObjectMakeArrayBufferWithBytesNoCopy(
+                ctx.raw,
+                pointer.cast(),
+                byte_length,
+                Some(deallocate::<B>),
+                Box::into_raw(bytes).cast(),
+                &mut exception,
+            )
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(ctx.raw, exception).into() });
+        }
+
+        if result.is_null() {
+            return Err(Self::new_string(ctx, "Failed to make a new array buffer").into());
+        }
+
+        Ok(unsafe { Self::from_raw(ctx.raw, result) })
+    }
+
     /// Creates a JavaScript function where the function implementation is written in
     /// Rust.
     ///
@@ -359,6 +652,11 @@ impl JSValue {
     ///
     /// assert_eq!(result.as_string().unwrap().to_string(), "Hello, Gordon!");
     /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSContext::make_function()`], to implement the function with a
+    ///   closure.
     pub fn new_function<N>(
         ctx: &JSContext,
         name: N,
@@ -391,6 +689,10 @@ impl JSValue {
     /// let v = JSValue::new_from_json(&ctx, "true").expect("value");
     /// assert!(v.is_boolean());
     /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::to_json_string()`]
     pub fn new_from_json<S: Into<JSString>>(ctx: &JSContext, string: S) -> Option<Self> {
         let value = unsafe { sys::JSValueMakeFromJSONString(ctx.raw, string.into().raw) };
 
@@ -408,7 +710,9 @@ impl JSValue {
     ///   The size of the indent is clamped to `10` spaces.
     ///
     /// Returns either a [`JSString`] with the result of serialization, or an
-    /// [exception](JSException) if one was thrown.
+    /// [exception](JSException) if one was thrown, e.g. for circular
+    /// references. Values that have no JSON representation, like `undefined`
+    /// or functions, also return an error.
     ///
     /// ```
     /// # use javascriptcore::*;
@@ -417,22 +721,185 @@ impl JSValue {
     /// let v = JSValue::new_boolean(&ctx, false);
     /// let s = v.to_json_string(0).unwrap();
     /// assert_eq!(s, "false");
+    ///
+    /// let v = JSValue::new_from_json(&ctx, r#"{"a": [1]}"#).unwrap();
+    /// let s = v.to_json_string(2).unwrap();
+    /// assert_eq!(s, "{\n  \"a\": [\n    1\n  ]\n}");
+    ///
+    /// assert!(JSValue::new_undefined(&ctx).to_json_string(0).is_err());
     /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_from_json()`]
     pub fn to_json_string(&self, indent: u32) -> Result<JSString, JSException> {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let value =
             unsafe { sys::JSValueCreateJSONString(self.ctx, self.raw, indent, &mut exception) };
 
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(self.ctx, exception) }.into());
+        }
+
         if value.is_null() {
-            Err(unsafe { Self::from_raw(self.ctx, exception) }.into())
-        } else {
-            Ok(JSString { raw: value })
+            return Err(Self::new_string_inner(
+                self.ctx,
+                "Cannot serialize this value to JSON: it has no JSON representation",
+            )
+            .into());
+        }
+
+        Ok(JSString { raw: value })
+    }
+
+    /// Creates a JavaScript value from a JSON formatted string, transforming
+    /// it with a reviver, like `JSON.parse(string, reviver)`.
+    ///
+    /// * `ctx`: The execution context to use.
+    /// * `string`: A value that can be converted into a [`JSString`] containing
+    ///   the JSON string to be parsed.
+    /// * `reviver`: A function called with each key and parsed value, from
+    ///   the innermost ones, which returns the value to use instead, or
+    ///   `undefined` to remove the property. It can be implemented in Rust
+    ///   with [`JSContext::make_function()`].
+    ///
+    /// Returns either the parsed value, or an [exception](JSException) if the
+    /// input is invalid or if one was thrown by `reviver`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let double = ctx.make_function("double", |ctx, _this, arguments| {
+    ///     Ok(JSValue::new_number(ctx, arguments[1].as_number()? * 2.))
+    /// });
+    ///
+    /// let v = JSValue::new_from_json_with_reviver(&ctx, "21", &double).unwrap();
+    /// assert_eq!(v.as_number().unwrap(), 42.);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_from_json()`]
+    pub fn new_from_json_with_reviver<S: Into<JSString>>(
+        ctx: &JSContext,
+        string: S,
+        reviver: &JSObject,
+    ) -> Result<Self, JSException> {
+        call_json_function(
+            ctx.raw,
+            "parse",
+            &[Self::new_string_inner(ctx.raw, string), reviver.into()],
+        )
+    }
+
+    /// Creates a JavaScript string containing the JSON serialized
+    /// representation of a JS value, transformed by a replacer, like
+    /// `JSON.stringify(value, replacer, indent)`.
+    ///
+    /// * `replacer`: Either a function called with each key and value, from
+    ///   the outermost ones, which returns the value to serialize instead, or
+    ///   `undefined` to skip the property; or an array of the property names
+    ///   to serialize. The function can be implemented in Rust with
+    ///   [`JSContext::make_function()`].
+    /// * `indent`: The number of spaces to indent when nesting.
+    ///   If `0`, the resulting JSON will not contains newlines.
+    ///   The size of the indent is clamped to `10` spaces.
+    ///
+    /// Returns as [`JSValue::to_json_string()`] does, or with the
+    /// [exception](JSException) thrown by `replacer`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let v = JSValue::new_from_json(&ctx, r#"{"a": 1, "b": 2, "c": 3}"#).unwrap();
+    /// let replacer = JSValue::new_from_json(&ctx, r#"["a", "c"]"#).unwrap().as_object().unwrap();
+    ///
+    /// let s = v.to_json_string_with_replacer(&replacer, 0).unwrap();
+    /// assert_eq!(s, r#"{"a":1,"c":3}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::to_json_string()`]
+    pub fn to_json_string_with_replacer(
+        &self,
+        replacer: &JSObject,
+        indent: u32,
+    ) -> Result<JSString, JSException> {
+        let value = call_json_function(
+            self.ctx,
+            "stringify",
+            &[
+                unsafe { Self::from_raw(self.ctx, self.raw) },
+                replacer.into(),
+                unsafe {
+                    Self::from_raw(
+                        self.ctx,
+                        sys::JSValueMakeNumber(self.ctx, f64::from(indent.min(10))),
+                    )
+                },
+            ],
+        )?;
+
+        if value.is_undefined() {
+            return Err(Self::new_string_inner(
+                self.ctx,
+                "Cannot serialize this value to JSON: it has no JSON representation",
+            )
+            .into());
+        }
+
+        value.as_string()
+    }
+
+    /// Creates a deep copy of a JavaScript value, by serializing it to JSON
+    /// and parsing it back, e.g. to snapshot an object graph that is mutated
+    /// afterwards.
+    ///
+    /// Nested objects and arrays are copied too, but the copy has the
+    /// limitations of JSON: functions, symbols and `undefined` properties
+    /// are dropped (or become `null` in arrays), `NaN` and infinities become
+    /// `null`, dates become strings, and prototypes, `Map`s and `Set`s are
+    /// lost. This isn't the HTML `structuredClone()`, which JavaScriptCore
+    /// doesn't provide outside of WebKit.
+    ///
+    /// Returns either the copy, or an [exception](JSException) if the value
+    /// can't be serialized, see [`JSValue::to_json_string()`].
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let original = JSValue::new_from_json(&ctx, r#"{"a": {"b": [1]}}"#).unwrap();
+    /// let copy = original.deep_clone().unwrap();
+    ///
+    /// original.as_object().unwrap().set_property("a", JSValue::new_null(&ctx)).unwrap();
+    /// assert_eq!(copy.to_json_string(0).unwrap(), r#"{"a":{"b":[1]}}"#);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::new_from_json()`]
+    /// - [`JSValue::to_json_string()`]
+    pub fn deep_clone(&self) -> Result<Self, JSException> {
+        let json = self.to_json_string(0)?;
+        let value = unsafe { sys::JSValueMakeFromJSONString(self.ctx, json.raw) };
+
+        if value.is_null() {
+            return Err(
+                Self::new_string_inner(self.ctx, "Cannot parse the JSON of this value").into(),
+            );
         }
+
+        Ok(unsafe { Self::from_raw(self.ctx, value) })
     }
 
     /// Returns a JavaScript value's type.
     ///
-    /// Returns a value of type `JSType` that identifies `value`'s type.
+    /// Returns a value of type `JSType` that identifies `value`'s type. It
+    /// is handy to `match` on it instead of calling each `is_*` method in
+    /// sequence. Note that [`JSType`] is non-exhaustive, since new types may
+    /// be added to JavaScript.
     ///
     /// ```
     /// # use javascriptcore::*;
@@ -451,6 +918,44 @@ impl JSValue {
         unsafe { sys::JSValueGetType(self.ctx, self.raw) }
     }
 
+    /// Returns the string the `typeof` operator of JavaScript produces for
+    /// this value.
+    ///
+    /// Contrary to [`JSValue::get_type()`], functions are reported as
+    /// `"function"` rather than `"object"`. Like `typeof`, `null` is
+    /// reported as `"object"`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let v = evaluate_script(&ctx, "(() => {})", None, "test.js", 1).unwrap();
+    /// assert_eq!(v.type_of(), "function");
+    ///
+    /// let v = JSValue::new_from_json(&ctx, "[1, 2]").expect("value");
+    /// assert_eq!(v.type_of(), "object");
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::get_type()`]
+    pub fn type_of(&self) -> &'static str {
+        match self.get_type() {
+            JSType::Undefined => "undefined",
+            JSType::Null => "object",
+            JSType::Boolean => "boolean",
+            JSType::Number => "number",
+            JSType::String => "string",
+            JSType::Symbol => "symbol",
+            JSType::BigInt => "bigint",
+            JSType::Object if self.is_big_int() => "bigint",
+            JSType::Object if unsafe { sys::JSObjectIsFunction(self.ctx, self.raw as *mut _) } => {
+                "function"
+            }
+            _ => "object",
+        }
+    }
+
     /// Tests whether a JavaScript value's type is the `undefined` type.
     ///
     /// Returns `true` if `value`'s type is the `undefined` type, otherwise `false`.
@@ -489,6 +994,29 @@ impl JSValue {
         unsafe { sys::JSValueIsNull(self.ctx, self.raw) }
     }
 
+    /// Tests whether a JavaScript value is `null` or `undefined`.
+    ///
+    /// Returns `true` for the values that the `??` operator of JavaScript
+    /// replaces, otherwise `false`. Contrary to [`JSValue::as_boolean()`],
+    /// falsy values like `0` or `""` aren't nullish.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// assert!(JSValue::new_null(&ctx).is_nullish());
+    /// assert!(JSValue::new_undefined(&ctx).is_nullish());
+    /// assert!(!JSValue::new_number(&ctx, 0.).is_nullish());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::is_null()`]
+    /// - [`JSValue::is_undefined()`]
+    pub fn is_nullish(&self) -> bool {
+        self.is_null() || self.is_undefined()
+    }
+
     /// Tests whether a JavaScript value's type is the `boolean` type.
     ///
     /// Returns `true` if `value`'s type is the `boolean` type, otherwise `false`.
@@ -568,22 +1096,49 @@ impl JSValue {
         unsafe { sys::JSValueIsSymbol(self.ctx, self.raw) }
     }
 
-    /// Tests whether a JavaScript value's type is the `object` type.
+    /// Tests whether a JavaScript value's type is the `bigint` type.
     ///
-    /// Returns `true` if `value`'s type is the `object` type, otherwise `false`.
+    /// Returns `true` if `value`'s type is the `bigint` type, otherwise `false`.
     ///
     /// ```
     /// # use javascriptcore::*;
     /// let ctx = JSContext::default();
     ///
-    /// let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("valid object");
-    /// assert!(v.is_object());
+    /// let v = JSValue::new_big_int_from_i64(&ctx, 42).unwrap();
+    /// assert!(v.is_big_int());
+    /// assert!(!JSValue::new_number(&ctx, 42.0).is_big_int());
     /// ```
     ///
     /// # See also
     ///
-    /// - [`JSValue::as_object()`]
-    /// - [`JSValue::is_object_of_class()`]
+    /// - [`JSValue::new_big_int_from_i64()`]
+    /// - [`JSValue::new_big_int_from_string()`]
+    pub fn is_big_int(&self) -> bool {
+        match self.get_type() {
+            JSType::BigInt => true,
+            // Older versions of JavaScriptCore report `BigInt`s as objects,
+            // while they aren't.
+            JSType::Object => !self.is_object(),
+            _ => false,
+        }
+    }
+
+    /// Tests whether a JavaScript value's type is the `object` type.
+    ///
+    /// Returns `true` if `value`'s type is the `object` type, otherwise `false`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("valid object");
+    /// assert!(v.is_object());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_object()`]
+    /// - [`JSValue::is_object_of_class()`]
     pub fn is_object(&self) -> bool {
         unsafe { sys::JSValueIsObject(self.ctx, self.raw) }
     }
@@ -603,6 +1158,45 @@ impl JSValue {
         unsafe { sys::JSValueIsObjectOfClass(self.ctx, self.raw, js_class.raw) }
     }
 
+    /// Tests whether a JavaScript value is an `object` constructed by a given
+    /// constructor, as compared by the JS `instanceof` operator.
+    ///
+    /// * `constructor`: The constructor to test against.
+    ///
+    /// Returns either `true` if `value` is an `object` constructed by
+    /// `constructor`, `false` if it is not, or an [exception](JSException)
+    /// if one was thrown (e.g. if `constructor` isn't callable).
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let array = evaluate_script(&ctx, "Array", None, "foo.js", 1).unwrap();
+    /// let array = array.as_object().unwrap();
+    ///
+    /// let v = JSValue::new_array(&ctx, &[]).unwrap();
+    /// assert!(v.is_instance_of(&array).unwrap());
+    ///
+    /// let v = JSValue::new_number(&ctx, 1.0);
+    /// assert!(!v.is_instance_of(&array).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::is_object_of_class()`]
+    pub fn is_instance_of(&self, constructor: &JSObject) -> Result<bool, JSException> {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let result = unsafe {
+            sys::JSValueIsInstanceOfConstructor(self.ctx, self.raw, constructor.raw, &mut exception)
+        };
+
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(self.ctx, exception) }.into());
+        }
+
+        Ok(result)
+    }
+
     /// Tests whether a JavaScript value is an `array`.
     ///
     /// Returns `true` if `value` is an `array`, otherwise `false`.
@@ -642,12 +1236,42 @@ impl JSValue {
     /// # See also
     ///
     /// - [`JSValue::as_typed_array()`]
+    /// - [`JSValue::is_array_buffer()`]
     /// - [`JSValue::new_typed_array_with_bytes()`]
     pub fn is_typed_array(&self) -> bool {
         let mut exception: sys::JSValueRef = ptr::null_mut();
         let value = unsafe { sys::JSValueGetTypedArrayType(self.ctx, self.raw, &mut exception) };
 
-        value != JSTypedArrayType::None
+        !matches!(
+            value,
+            JSTypedArrayType::None | JSTypedArrayType::ArrayBuffer
+        )
+    }
+
+    /// Tests whether a JavaScript value is an `ArrayBuffer`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let value = evaluate_script(&ctx, "new ArrayBuffer(8)", None, "foo.js", 1).unwrap();
+    /// assert!(value.is_array_buffer());
+    /// assert!(!value.is_typed_array());
+    ///
+    /// let value = evaluate_script(&ctx, "new Uint8Array(8)", None, "foo.js", 1).unwrap();
+    /// assert!(!value.is_array_buffer());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSObject::array_buffer_bytes()`]
+    /// - [`JSValue::is_typed_array()`]
+    /// - [`JSValue::new_array_buffer()`]
+    pub fn is_array_buffer(&self) -> bool {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let value = unsafe { sys::JSValueGetTypedArrayType(self.ctx, self.raw, &mut exception) };
+
+        value == JSTypedArrayType::ArrayBuffer
     }
 
     /// Tests whether a JavaScript value is a `date`.
@@ -657,9 +1281,64 @@ impl JSValue {
         unsafe { sys::JSValueIsDate(self.ctx, self.raw) }
     }
 
+    /// Tests whether two JavaScript values are strict equal, as compared by
+    /// the JS `===` operator.
+    ///
+    /// Returns `true` if the two values are strict equal, otherwise `false`.
+    /// This is also what [`PartialEq`] uses.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let one = JSValue::new_number(&ctx, 1.0);
+    /// assert!(one.strict_equals(&JSValue::new_number(&ctx, 1.0)));
+    /// assert!(!one.strict_equals(&JSValue::new_string(&ctx, "1")));
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::equals()`]
+    pub fn strict_equals(&self, other: &JSValue) -> bool {
+        unsafe { sys::JSValueIsStrictEqual(self.ctx, self.raw, other.raw) }
+    }
+
+    /// Tests whether two JavaScript values are equal, as compared by the JS
+    /// `==` operator.
+    ///
+    /// Returns either `true` if the two values are equal, `false` if they
+    /// are not, or an [exception](JSException) if one was thrown while
+    /// converting the values (e.g. by a `valueOf` method).
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let one = JSValue::new_number(&ctx, 1.0);
+    /// assert!(one.equals(&JSValue::new_string(&ctx, "1")).unwrap());
+    /// assert!(!one.equals(&JSValue::new_string(&ctx, "2")).unwrap());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::strict_equals()`]
+    pub fn equals(&self, other: &JSValue) -> Result<bool, JSException> {
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let result = unsafe { sys::JSValueIsEqual(self.ctx, self.raw, other.raw, &mut exception) };
+
+        if !exception.is_null() {
+            return Err(unsafe { Self::from_raw(self.ctx, exception) }.into());
+        }
+
+        Ok(result)
+    }
+
     /// Converts a JavaScript value to boolean and returns the resulting boolean.
     ///
-    /// Returns the boolean result of conversion.
+    /// Returns the boolean result of conversion, which follows the JavaScript
+    /// truthiness, as in `if (value)`: `false`, `0`, `-0`, `NaN`, `0n`, `""`,
+    /// `null` and `undefined` are falsy, all the other values are truthy,
+    /// objects included. The conversion never throws.
     ///
     /// ```
     /// # use javascriptcore::*;
@@ -667,10 +1346,15 @@ impl JSValue {
     ///
     /// let v = JSValue::new_boolean(&ctx, false);
     /// assert_eq!(v.as_boolean(), false);
+    ///
+    /// assert!(!JSValue::new_string(&ctx, "").as_boolean());
+    /// assert!(JSValue::new_string(&ctx, "false").as_boolean());
+    /// assert!(!JSValue::new_number(&ctx, 0.).as_boolean());
     /// ```
     ///
     /// # See also
     ///
+    /// - [`JSValue::as_bool()`], to get booleans without conversion.
     /// - [`JSValue::is_boolean()`]
     /// - [`JSValue::new_boolean()`]
     pub fn as_boolean(&self) -> bool {
@@ -709,7 +1393,12 @@ impl JSValue {
     /// Converts a JavaScript value to string and copies the result into a JavaScript string.
     ///
     /// Returns either [`JSString`] with the result of conversion, or an
-    /// [exception](JSException) if one was thrown.  Ownership follows the Create Rule.
+    /// [exception](JSException) if one was thrown, e.g. by a `toString`
+    /// method.  Ownership follows the Create Rule.
+    ///
+    /// The result stays a JavaScript string: no UTF-8 conversion happens
+    /// until it is needed, e.g. with `to_string()`, which is cheap for large
+    /// strings only passed back to JavaScript.
     ///
     /// ```
     /// # use javascriptcore::*;
@@ -735,10 +1424,240 @@ impl JSValue {
         }
     }
 
+    /// Converts a JavaScript value to a primitive value, like JavaScript does
+    /// before e.g. an arithmetic operation.
+    ///
+    /// Primitive values are returned as is. Objects are converted by their
+    /// `[Symbol.toPrimitive]()` method, called with `hint`, if they have one,
+    /// or else by their `valueOf()` and `toString()` methods, tried in the
+    /// order of `hint`.
+    ///
+    /// Returns either the primitive value, or an [exception](JSException) if
+    /// one was thrown, e.g. by one of these methods, or if they all return
+    /// objects.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let object = evaluate_script(
+    ///     &ctx,
+    ///     "({ valueOf() { return 42; }, toString() { return 'forty-two'; } })",
+    ///     None,
+    ///     "foo.js",
+    ///     1,
+    /// )
+    /// .unwrap();
+    ///
+    /// let number = object.to_primitive(JSPrimitiveHint::Number).unwrap();
+    /// assert_eq!(number.as_number().unwrap(), 42.);
+    ///
+    /// let string = object.to_primitive(JSPrimitiveHint::String).unwrap();
+    /// assert_eq!(string.as_string().unwrap(), "forty-two");
+    /// ```
+    pub fn to_primitive(&self, hint: JSPrimitiveHint) -> Result<Self, JSException> {
+        // The C API of JavaScriptCore has no `ToPrimitive`, so it's done in
+        // JavaScript. `Date.prototype[Symbol.toPrimitive]()` is the ordinary
+        // conversion, except that it treats the `default` hint as `string`.
+        const TO_PRIMITIVE: &str = r#"
+            (value, hint) => {
+                if (Object(value) !== value) {
+                    return value;
+                }
+
+                const toPrimitive = value[Symbol.toPrimitive];
+
+                if (toPrimitive != null) {
+                    const result = toPrimitive.call(value, hint);
+
+                    if (Object(result) === result) {
+                        throw new TypeError("Cannot convert object to primitive value");
+                    }
+
+                    return result;
+                }
+
+                return Date.prototype[Symbol.toPrimitive].call(
+                    value,
+                    hint === "default" ? "number" : hint,
+                );
+            }
+        "#;
+
+        let script = JSString::from(TO_PRIMITIVE);
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+        let function = unsafe {
+            sys::JSEvaluateScript(
+                self.ctx,
+                script.raw,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                1,
+                &mut exception,
+            )
+        };
+
+        if function.is_null() {
+            return Err(unsafe { Self::from_raw(self.ctx, exception) }.into());
+        }
+
+        let hint = match hint {
+            JSPrimitiveHint::Default => "default",
+            JSPrimitiveHint::Number => "number",
+            JSPrimitiveHint::String => "string",
+        };
+
+        unsafe { Self::from_raw(self.ctx, function) }
+            .as_object()?
+            .call_as_function(
+                None,
+                &[
+                    unsafe { Self::from_raw(self.ctx, self.raw) },
+                    Self::new_string_inner(self.ctx, hint),
+                ],
+            )
+    }
+
+    /// Returns the number held by a JavaScript value of the `number` type.
+    ///
+    /// Contrary to [`JSValue::as_number()`], no conversion happens: `None` is
+    /// returned if the value isn't of the `number` type. Note that `NaN` is a
+    /// valid number, and thus returns `Some(f64::NAN)`.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// assert_eq!(JSValue::new_number(&ctx, 5.0).as_f64(), Some(5.0));
+    /// assert!(JSValue::new_number(&ctx, f64::NAN).as_f64().unwrap().is_nan());
+    /// assert_eq!(JSValue::new_string(&ctx, "5").as_f64(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_number()`]
+    /// - [`JSValue::is_number()`]
+    pub fn as_f64(&self) -> Option<f64> {
+        if !self.is_number() {
+            return None;
+        }
+
+        let mut exception: sys::JSValueRef = ptr::null_mut();
+
+        Some(unsafe { sys::JSValueToNumber(self.ctx, self.raw, &mut exception) })
+    }
+
+    /// Returns the boolean held by a JavaScript value of the `boolean` type.
+    ///
+    /// Contrary to [`JSValue::as_boolean()`], no conversion happens: `None` is
+    /// returned if the value isn't of the `boolean` type.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// assert_eq!(JSValue::new_boolean(&ctx, true).as_bool(), Some(true));
+    /// assert_eq!(JSValue::new_number(&ctx, 1.0).as_bool(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_boolean()`]
+    /// - [`JSValue::is_boolean()`]
+    pub fn as_bool(&self) -> Option<bool> {
+        if !self.is_boolean() {
+            return None;
+        }
+
+        Some(unsafe { sys::JSValueToBoolean(self.ctx, self.raw) })
+    }
+
+    /// Returns a copy of the string held by a JavaScript value of the `string`
+    /// type.
+    ///
+    /// Contrary to [`JSValue::as_string()`], no conversion happens: `None` is
+    /// returned if the value isn't of the `string` type. The string is copied
+    /// with `JSValueToStringCopy`, so it allocates a new [`JSString`].
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// assert_eq!(JSValue::new_string(&ctx, "abc").as_js_string().unwrap(), "abc");
+    /// assert!(JSValue::new_number(&ctx, 1.0).as_js_string().is_none());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_string()`]
+    /// - [`JSValue::is_string()`]
+    pub fn as_js_string(&self) -> Option<JSString> {
+        if !self.is_string() {
+            return None;
+        }
+
+        self.as_string().ok()
+    }
+
+    /// Returns the integer held by a JavaScript value of the `bigint` type.
+    ///
+    /// No conversion happens: `None` is returned if the value isn't of the
+    /// `bigint` type, or if its integer doesn't fit in an `i64`. Use
+    /// [`JSValue::as_string()`] to get `BigInt`s of any size.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    ///
+    /// let v = JSValue::new_big_int_from_i64(&ctx, -42).unwrap();
+    /// assert_eq!(v.as_i64(), Some(-42));
+    /// assert_eq!(JSValue::new_number(&ctx, 42.0).as_i64(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::is_big_int()`]
+    /// - [`JSValue::new_big_int_from_i64()`]
+    pub fn as_i64(&self) -> Option<i64> {
+        if !self.is_big_int() {
+            return None;
+        }
+
+        self.as_string().ok()?.to_string().parse().ok()
+    }
+
+    /// Extracts a Rust value of the type `T` from a JavaScript value.
+    ///
+    /// No conversion happens: an [exception](JSException) is returned if the
+    /// value isn't of the matching type. See [`FromJSValue`] for the
+    /// supported types.
+    ///
+    /// ```
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let value = JSValue::new_number(&ctx, 5.0);
+    ///
+    /// let number: f64 = value.extract().unwrap();
+    /// assert_eq!(number, 5.0);
+    ///
+    /// assert!(value.extract::<String>().is_err());
+    /// assert_eq!(JSValue::new_null(&ctx).extract::<Option<f64>>().unwrap(), None);
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSValue::as_bool()`]
+    /// - [`JSValue::as_f64()`]
+    /// - [`JSValue::as_js_string()`]
+    pub fn extract<T: FromJSValue>(&self) -> Result<T, JSException> {
+        T::from_js_value(self)
+    }
+
     /// Converts a JavaScript value to object and returns the resulting object.
     ///
     /// Returns either the `JSObject` result of conversion, or an [exception](JSException)
-    /// if one was thrown.
+    /// if one was thrown. Objects are returned as is, primitive values are
+    /// wrapped in objects (like `Object(value)` in JavaScript), while `null`
+    /// and `undefined` throw a `TypeError`.
     ///
     /// ```
     /// # use javascriptcore::*;
@@ -747,6 +1666,8 @@ impl JSValue {
     /// let v = JSValue::new_from_json(&ctx, "{\"id\": 123}").expect("valid object");
     /// let o = v.as_object().expect("object");
     /// // We now have an object that we can inspect.
+    ///
+    /// assert!(JSValue::new_null(&ctx).as_object().is_err());
     /// ```
     ///
     /// # See also
@@ -807,6 +1728,52 @@ impl JSValue {
         Ok(unsafe { JSTypedArray::from_raw(object.ctx, object.raw) })
     }
 
+    /// Converts a JavaScript value to a `Map` object.
+    ///
+    /// Returns an [exception](JSException) if the value isn't a `Map`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let map = evaluate_script(&ctx, "new Map([[1, 'one']])", None, "foo.js", 1).unwrap();
+    ///
+    /// assert_eq!(map.as_map().unwrap().size().unwrap(), 1);
+    /// assert!(JSValue::new_from_json(&ctx, "{}").unwrap().as_map().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSMap::new()`]
+    pub fn as_map(&self) -> Result<JSMap, JSException> {
+        self.as_object()
+            .ok()
+            .and_then(JSMap::from_object)
+            .ok_or_else(|| Self::new_string_inner(self.ctx, "Value is not a Map").into())
+    }
+
+    /// Converts a JavaScript value to a `Set` object.
+    ///
+    /// Returns an [exception](JSException) if the value isn't a `Set`.
+    ///
+    /// ```rust
+    /// # use javascriptcore::*;
+    /// let ctx = JSContext::default();
+    /// let set = evaluate_script(&ctx, "new Set([1, 2, 2])", None, "foo.js", 1).unwrap();
+    ///
+    /// assert_eq!(set.as_set().unwrap().size().unwrap(), 2);
+    /// assert!(JSValue::new_from_json(&ctx, "[]").unwrap().as_set().is_err());
+    /// ```
+    ///
+    /// # See also
+    ///
+    /// - [`JSSet::new()`]
+    pub fn as_set(&self) -> Result<JSSet, JSException> {
+        self.as_object()
+            .ok()
+            .and_then(JSSet::from_object)
+            .ok_or_else(|| Self::new_string_inner(self.ctx, "Value is not a Set").into())
+    }
+
     /// Protects a JavaScript value from garbage collection.
     ///
     /// Use this method when you want to store a [`JSValue`] in a
@@ -817,12 +1784,15 @@ impl JSValue {
     /// [unprotected] an equal number of times before becoming
     /// eligible for garbage collection.
     ///
+    /// Prefer [`Protected`], which unprotects the value when dropped.
+    ///
     /// # See also
     ///
     /// * [`garbage_collect()`]
     /// * [`JSValue::unprotect()`]
     ///
     /// [`garbage_collect()`]: crate::garbage_collect
+    /// [`Protected`]: crate::Protected
     /// [unprotected]: JSValue::unprotect
     pub fn protect(&self) {
         unsafe { sys::JSValueProtect(self.ctx, self.raw) };
@@ -846,13 +1816,180 @@ impl JSValue {
     }
 }
 
+/// A number formatted like JavaScript does, e.g. by
+/// `Number.prototype.toString()` or in template literals, without calling
+/// JavaScriptCore.
+///
+/// Integers have no decimal point, and numbers from `1e21` on, or below
+/// `1e-6`, use the exponential notation. The digits are the shortest ones
+/// which round-trip, like Rust's formatting of `f64`.
+///
+/// ```rust
+/// # use javascriptcore::JSNumberDisplay;
+/// assert_eq!(JSNumberDisplay(1.).to_string(), "1");
+/// assert_eq!(JSNumberDisplay(0.1 + 0.2).to_string(), "0.30000000000000004");
+/// assert_eq!(JSNumberDisplay(1e21).to_string(), "1e+21");
+/// assert_eq!(JSNumberDisplay(f64::NAN).to_string(), "NaN");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct JSNumberDisplay(pub f64);
+
+impl fmt::Display for JSNumberDisplay {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let number = self.0;
+
+        if number.is_nan() {
+            return fmt.pad("NaN");
+        }
+
+        // `-0` is formatted as `0`.
+        if number == 0. {
+            return fmt.pad("0");
+        }
+
+        if number.is_infinite() {
+            return fmt.pad(if number > 0. { "Infinity" } else { "-Infinity" });
+        }
+
+        // The shortest digits, e.g. `"15"` and `0` for `1.5`, from which
+        // `Number::toString` of the specification formats the number.
+        let exponential = format!("{:e}", number.abs());
+        let (mantissa, exponent) = exponential
+            .split_once('e')
+            .expect("an `f64` in exponential notation has an exponent");
+        let digits = mantissa.replace('.', "");
+        let exponent: i32 = exponent.parse().expect("the exponent is an integer");
+        // The number is `0.{digits} × 10^point`.
+        let point = exponent + 1;
+        let length = digits.len() as i32;
+
+        let mut output = String::new();
+
+        if number < 0. {
+            output.push('-');
+        }
+
+        if length <= point && point <= 21 {
+            output.push_str(&digits);
+            output.extend((length..point).map(|_| '0'));
+        } else if 0 < point && point <= 21 {
+            let (integer, fraction) = digits.split_at(point as usize);
+            output.push_str(integer);
+            output.push('.');
+            output.push_str(fraction);
+        } else if -6 < point && point <= 0 {
+            output.push_str("0.");
+            output.extend((point..0).map(|_| '0'));
+            output.push_str(&digits);
+        } else {
+            let (first, rest) = digits.split_at(1);
+            output.push_str(first);
+
+            if !rest.is_empty() {
+                output.push('.');
+                output.push_str(rest);
+            }
+
+            output.push('e');
+            output.push(if exponent < 0 { '-' } else { '+' });
+            output.push_str(&exponent.abs().to_string());
+        }
+
+        fmt.pad(&output)
+    }
+}
+
+/// Calls the function `name` of the global `JSON` object.
+fn call_json_function(
+    ctx: sys::JSContextRef,
+    name: &str,
+    arguments: &[JSValue],
+) -> Result<JSValue, JSException> {
+    let global_object = unsafe { JSObject::from_raw(ctx, sys::JSContextGetGlobalObject(ctx)) };
+    let json = global_object.try_get_property("JSON")?.as_object()?;
+    let function = json.try_get_property(name)?.as_object()?;
+
+    function.call_as_function(Some(&json), arguments)
+}
+
+/// The preferred type of a conversion to a primitive value, see
+/// [`JSValue::to_primitive()`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum JSPrimitiveHint {
+    /// No preference, like for the `+` and `==` operators. Objects without
+    /// a `[Symbol.toPrimitive]()` method are converted like with `Number`,
+    /// except for `Date`s, which are converted like with `String`.
+    Default,
+    /// A number is preferred, like for arithmetic operators but `+`.
+    Number,
+    /// A string is preferred, like for template literals.
+    String,
+}
+
+/// A value is debugged as a JavaScript-like representation: e.g. `42`, `"abc"`
+/// or `undefined` for primitive values, and its JSON serialization for objects.
+/// Objects which have no JSON representation, like functions or circular
+/// objects, are debugged as `[object]`.
+///
+/// Serializing an object to JSON calls its `toJSON` method, if any.
+///
+/// ```
+/// # use javascriptcore::*;
+/// let ctx = JSContext::default();
+///
+/// assert_eq!(format!("{:?}", JSValue::new_string(&ctx, "abc")), r#"JSValue { "abc" }"#);
+/// assert_eq!(format!("{:?}", JSValue::new_from_json(&ctx, "[1]").unwrap()), "JSValue { [1] }");
+/// ```
+impl fmt::Debug for JSValue {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "JSValue {{ ")?;
+
+        match self.get_type() {
+            JSType::Undefined => write!(fmt, "undefined")?,
+            JSType::Null => write!(fmt, "null")?,
+            JSType::Boolean => write!(fmt, "{}", self.as_boolean())?,
+            JSType::String => match self.as_string() {
+                Ok(string) => write!(fmt, "{:?}", string.to_string())?,
+                Err(_) => write!(fmt, "[string]")?,
+            },
+            JSType::Symbol => {
+                let description = self
+                    .as_object()
+                    .and_then(|symbol| symbol.try_get_property("description"))
+                    .ok()
+                    .filter(|description| !description.is_undefined())
+                    .and_then(|description| description.as_string().ok());
+
+                match description {
+                    Some(description) => write!(fmt, "Symbol({description})")?,
+                    None => write!(fmt, "Symbol()")?,
+                }
+            }
+            _ if self.is_big_int() => match self.as_string() {
+                Ok(string) => write!(fmt, "{string}n")?,
+                Err(_) => write!(fmt, "[bigint]")?,
+            },
+            JSType::Number => match self.as_f64() {
+                Some(number) => write!(fmt, "{}", JSNumberDisplay(number))?,
+                None => write!(fmt, "[number]")?,
+            },
+            _ => match self.to_json_string(0) {
+                Ok(json) => write!(fmt, "{json}")?,
+                Err(_) => write!(fmt, "[object]")?,
+            },
+        }
+
+        write!(fmt, " }}")
+    }
+}
+
 /// Implement partial equality checks for `JSValue`.
 ///
 /// These are performed in the same manner as `===` (strict
 /// equality) in JavaScript.
 impl PartialEq for JSValue {
     fn eq(&self, other: &JSValue) -> bool {
-        unsafe { sys::JSValueIsStrictEqual(self.ctx, self.raw, other.raw) }
+        self.strict_equals(other)
     }
 }
 
@@ -870,7 +2007,10 @@ impl From<JSValue> for sys::JSObjectRef {
 
 #[cfg(test)]
 mod tests {
-    use crate::{evaluate_script, function_callback, sys, JSContext, JSException, JSType, JSValue};
+    use crate::{
+        evaluate_script, function_callback, sys, JSContext, JSException, JSNumberDisplay,
+        JSPrimitiveHint, JSType, JSValue,
+    };
 
     #[test]
     fn strict_equality() {
@@ -887,6 +2027,60 @@ mod tests {
         assert_ne!(t, f);
     }
 
+    #[test]
+    fn get_type() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        let types = [
+            ("undefined", JSType::Undefined),
+            ("null", JSType::Null),
+            ("true", JSType::Boolean),
+            ("42", JSType::Number),
+            ("'abc'", JSType::String),
+            ("({})", JSType::Object),
+            ("[]", JSType::Object),
+            ("(function () {})", JSType::Object),
+            ("Symbol('abc')", JSType::Symbol),
+        ];
+
+        for (script, expected_type) in types {
+            let value = evaluate_script(&ctx, script, None, "test.js", 1)?;
+            assert_eq!(value.get_type(), expected_type, "for `{script}`");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn type_of() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        let types = [
+            ("undefined", "undefined"),
+            ("null", "object"),
+            ("true", "boolean"),
+            ("42", "number"),
+            ("'abc'", "string"),
+            ("({})", "object"),
+            ("[]", "object"),
+            ("(function () {})", "function"),
+            ("(class {})", "function"),
+            ("Math.max", "function"),
+            ("Symbol('abc')", "symbol"),
+            ("42n", "bigint"),
+        ];
+
+        for (script, expected_type) in types {
+            let value = evaluate_script(&ctx, script, None, "test.js", 1)?;
+            assert_eq!(value.type_of(), expected_type, "for `{script}`");
+
+            let type_of = evaluate_script(&ctx, format!("typeof {script}"), None, "test.js", 1)?;
+            assert_eq!(type_of.as_string()?, expected_type, "for `{script}`");
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn undefined() {
         let ctx = JSContext::default();
@@ -909,6 +2103,20 @@ mod tests {
         assert_eq!(vn.as_string().unwrap(), "null");
     }
 
+    #[test]
+    fn nullish() {
+        let ctx = JSContext::default();
+        let vn = JSValue::new_null(&ctx);
+        let vu = JSValue::new_undefined(&ctx);
+        let vz = JSValue::new_number(&ctx, 0.);
+
+        assert!(vn.is_null() && !vn.is_undefined() && vn.is_nullish());
+        assert!(!vu.is_null() && vu.is_undefined() && vu.is_nullish());
+        assert!(!vz.is_null() && !vz.is_undefined() && !vz.is_nullish());
+        assert!(!JSValue::new_string(&ctx, "").is_nullish());
+        assert!(!JSValue::new_boolean(&ctx, false).is_nullish());
+    }
+
     #[test]
     fn boolean() {
         let ctx = JSContext::default();
@@ -941,6 +2149,60 @@ mod tests {
         assert_eq!(vn.as_string().unwrap(), "30.4");
     }
 
+    #[test]
+    fn number_round_trip() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        for n in [
+            0.0,
+            -0.0,
+            f64::MIN_POSITIVE,
+            f64::MAX,
+            f64::INFINITY,
+            9007199254740992.0,  // 2^53
+            9007199254740994.0,  // 2^53 + 2
+            18014398509481984.0, // 2^54
+        ] {
+            let v = JSValue::new_number(&ctx, n);
+            assert_eq!(v.as_number()?.to_bits(), n.to_bits());
+        }
+
+        // 2^53 + 1 isn't representable, neither in Rust nor in JavaScript.
+        let v = evaluate_script(&ctx, "9007199254740993", None, "foo.js", 1)?;
+        assert_eq!(v.as_number()?, 9007199254740992.0);
+        assert_eq!(v.as_number()?, 9007199254740993u64 as f64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn as_f64_as_bool_as_js_string() {
+        let ctx = JSContext::default();
+
+        let n = JSValue::new_number(&ctx, 30.4);
+        assert_eq!(n.as_f64(), Some(30.4));
+        assert_eq!(n.as_bool(), None);
+        assert!(n.as_js_string().is_none());
+
+        let nan = JSValue::new_number(&ctx, f64::NAN);
+        assert!(nan.as_f64().unwrap().is_nan());
+
+        let b = JSValue::new_boolean(&ctx, false);
+        assert_eq!(b.as_f64(), None);
+        assert_eq!(b.as_bool(), Some(false));
+        assert!(b.as_js_string().is_none());
+
+        let s = JSValue::new_string(&ctx, "42");
+        assert_eq!(s.as_f64(), None);
+        assert_eq!(s.as_bool(), None);
+        assert_eq!(s.as_js_string().unwrap(), "42");
+
+        let u = JSValue::new_undefined(&ctx);
+        assert_eq!(u.as_f64(), None);
+        assert_eq!(u.as_bool(), None);
+        assert!(u.as_js_string().is_none());
+    }
+
     #[test]
     fn string() {
         let ctx = JSContext::default();
@@ -953,6 +2215,346 @@ mod tests {
         assert_eq!(vs.as_string().unwrap(), "abc");
     }
 
+    #[test]
+    fn string_conversion() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        assert_eq!(JSValue::new_number(&ctx, 42.).as_string()?, "42");
+        assert_eq!(JSValue::new_number(&ctx, 0.5).as_string()?, "0.5");
+        assert_eq!(JSValue::new_null(&ctx).as_string()?, "null");
+
+        let object = evaluate_script(
+            &ctx,
+            "({ toString() { return 'custom'; } })",
+            None,
+            "foo.js",
+            1,
+        )?;
+        assert_eq!(object.as_string()?, "custom");
+
+        let malicious = evaluate_script(
+            &ctx,
+            "({ toString() { throw new Error('no way'); } })",
+            None,
+            "foo.js",
+            1,
+        )?;
+        let exception = malicious.as_string().unwrap_err();
+        assert_eq!(exception.to_string(), "Error: no way");
+
+        // Symbols can't be converted implicitly.
+        assert!(JSValue::new_symbol(&ctx, "foo").as_string().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_with_replacer_and_reviver() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let user = JSValue::new_from_json(
+            &ctx,
+            r#"{"name": "Ada", "password": "hunter2", "friends": [{"password": "x"}]}"#,
+        )
+        .unwrap();
+
+        let replacer = ctx.make_function("replacer", |ctx, _this, arguments| {
+            let [key, value] = arguments else {
+                return Err(JSValue::new_string(ctx, "Expected a key and a value").into());
+            };
+
+            // Drops the sensitive fields, at any depth.
+            if key.as_string()? == "password" {
+                return Ok(JSValue::new_undefined(ctx));
+            }
+
+            Ok(unsafe { JSValue::from_raw(ctx.as_raw(), value.as_raw()) })
+        });
+
+        assert_eq!(
+            user.to_json_string_with_replacer(&replacer, 0)?,
+            r#"{"name":"Ada","friends":[{}]}"#
+        );
+        assert_eq!(
+            user.to_json_string_with_replacer(&replacer, 20)?,
+            user.to_json_string_with_replacer(&replacer, 10)?
+        );
+
+        let failing = ctx.make_function("failing", |ctx, _this, _arguments| {
+            Err(JSValue::new_string(ctx, "nope").into())
+        });
+        assert!(user.to_json_string_with_replacer(&failing, 0).is_err());
+
+        let reviver = ctx.make_function("reviver", |ctx, _this, arguments| {
+            // Upper-cases the strings.
+            match arguments[1].as_js_string() {
+                Some(string) => Ok(JSValue::new_string(ctx, string.to_string().to_uppercase())),
+                None => Ok(unsafe { JSValue::from_raw(ctx.as_raw(), arguments[1].as_raw()) }),
+            }
+        });
+
+        let value = JSValue::new_from_json_with_reviver(&ctx, r#"{"a": ["b", 1]}"#, &reviver)?;
+        assert_eq!(value.to_json_string(0)?, r#"{"a":["B",1]}"#);
+        assert!(JSValue::new_from_json_with_reviver(&ctx, "{", &reviver).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn number_display() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let numbers = [
+            1.,
+            -1.,
+            1.5,
+            -0.,
+            0.1,
+            100.,
+            123456789.,
+            1e20,
+            1e21,
+            1.5e21,
+            -1e21,
+            1e-6,
+            1.5e-6,
+            1e-7,
+            1.25e-7,
+            0.000123,
+            5e-324,
+            f64::MAX,
+            f64::MIN_POSITIVE,
+            9007199254740993.,
+            f64::NAN,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ];
+
+        for number in numbers {
+            // JavaScriptCore formats numbers like the specification says.
+            let expected = JSValue::new_number(&ctx, number).as_string()?;
+
+            assert_eq!(
+                JSNumberDisplay(number).to_string(),
+                expected.to_string(),
+                "for {number:?}"
+            );
+        }
+
+        assert_eq!(JSNumberDisplay(1.).to_string(), "1");
+        assert_eq!(JSNumberDisplay(1.5).to_string(), "1.5");
+        assert_eq!(JSNumberDisplay(1e21).to_string(), "1e+21");
+        assert_eq!(JSNumberDisplay(1e-7).to_string(), "1e-7");
+        assert_eq!(JSNumberDisplay(f64::NAN).to_string(), "NaN");
+        assert_eq!(format!("{:>5}", JSNumberDisplay(1.5)), "  1.5");
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_primitive() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let convert = |script: &str, hint| -> Result<String, JSException> {
+            let value = evaluate_script(&ctx, script, None, "foo.js", 1)?.to_primitive(hint)?;
+
+            Ok(format!("{}:{}", value.type_of(), value.as_string()?))
+        };
+
+        let both = "({
+            [Symbol.toPrimitive](hint) { return hint === 'number' ? 1 : `hint ${hint}`; },
+            toString() { return 'toString'; },
+        })";
+        assert_eq!(convert(both, JSPrimitiveHint::Number)?, "number:1");
+        assert_eq!(
+            convert(both, JSPrimitiveHint::String)?,
+            "string:hint string"
+        );
+        assert_eq!(
+            convert(both, JSPrimitiveHint::Default)?,
+            "string:hint default"
+        );
+
+        // The string conversion honors `Symbol.toPrimitive` over `toString`.
+        let value = evaluate_script(&ctx, both, None, "foo.js", 1)?;
+        assert_eq!(value.as_string()?, "hint string");
+
+        let ordinary = "({ valueOf() { return 2; }, toString() { return 'two'; } })";
+        assert_eq!(convert(ordinary, JSPrimitiveHint::Number)?, "number:2");
+        assert_eq!(convert(ordinary, JSPrimitiveHint::String)?, "string:two");
+        assert_eq!(convert(ordinary, JSPrimitiveHint::Default)?, "number:2");
+
+        // `Date`s prefer strings.
+        assert!(convert("new Date(0)", JSPrimitiveHint::Default)?.starts_with("string:"));
+        assert_eq!(convert("new Date(0)", JSPrimitiveHint::Number)?, "number:0");
+
+        // Primitive values are kept as is.
+        assert_eq!(convert("'3'", JSPrimitiveHint::Number)?, "string:3");
+
+        let invalid = "({ [Symbol.toPrimitive]() { return {}; } })";
+        let value = evaluate_script(&ctx, invalid, None, "foo.js", 1)?;
+        assert!(value.to_primitive(JSPrimitiveHint::Default).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn symbol() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let a = JSValue::new_symbol(&ctx, "foo");
+        let b = JSValue::new_symbol(&ctx, "foo");
+
+        assert!(a.is_symbol());
+        assert!(!a.is_string());
+        assert!(!a.is_object());
+        assert_eq!(a.get_type(), JSType::Symbol);
+
+        // Same description, but different symbols.
+        assert_ne!(a, b);
+        assert!(!unsafe { sys::JSValueIsStrictEqual(ctx.raw, a.raw, b.raw) });
+        assert!(unsafe { sys::JSValueIsStrictEqual(ctx.raw, a.raw, a.raw) });
+
+        let global_object = ctx.global_object()?;
+        global_object.set_property("a", a)?;
+        global_object.set_property("b", b)?;
+
+        let result = evaluate_script(
+            &ctx,
+            "[a.description, a === b, typeof a]",
+            None,
+            "foo.js",
+            1,
+        )?;
+        assert_eq!(result.to_json_string(0)?, r#"["foo",false,"symbol"]"#);
+
+        // Symbols can be used as property keys.
+        let result = evaluate_script(
+            &ctx,
+            "const o = { [a]: 1 }; [o[a], o[b]]",
+            None,
+            "foo.js",
+            1,
+        )?;
+        assert_eq!(result.to_json_string(0)?, "[1,null]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn big_int() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        // 2^53 + 1 can't be represented by a `f64`.
+        let integer = 9_007_199_254_740_993_i64;
+        assert!(integer > i64::from(i32::MAX));
+
+        let value = JSValue::new_big_int_from_i64(&ctx, integer)?;
+        assert!(value.is_big_int());
+        assert!(!value.is_number());
+        assert!(!value.is_object());
+        assert_eq!(value.as_i64(), Some(integer));
+        assert_eq!(value.as_string()?, "9007199254740993");
+        assert!(value.as_number().is_err());
+
+        ctx.global_object()?.set_property("value", value)?;
+        let result = evaluate_script(
+            &ctx,
+            "[typeof value, value === 9007199254740993n]",
+            None,
+            "foo.js",
+            1,
+        )?;
+        assert_eq!(result.to_json_string(0)?, r#"["bigint",true]"#);
+
+        for integer in [0, -1, i64::MIN, i64::MAX] {
+            let value = JSValue::new_big_int_from_i64(&ctx, integer)?;
+            assert_eq!(value.as_i64(), Some(integer));
+        }
+
+        let value = evaluate_script(&ctx, "2n ** 64n", None, "foo.js", 1)?;
+        assert!(value.is_big_int());
+        assert_eq!(value.as_i64(), None);
+        assert_eq!(value.as_string()?, "18446744073709551616");
+
+        let value = JSValue::new_big_int_from_string(&ctx, "0xff")?;
+        assert_eq!(value.as_i64(), Some(255));
+        assert!(JSValue::new_big_int_from_string(&ctx, "foo").is_err());
+
+        assert!(!JSValue::new_number(&ctx, 1.0).is_big_int());
+        assert!(!JSValue::new_from_json(&ctx, "{}").unwrap().is_big_int());
+        assert_eq!(JSValue::new_string(&ctx, "1").as_i64(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn equality() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let one = JSValue::new_number(&ctx, 1.0);
+        let one_string = JSValue::new_string(&ctx, "1");
+
+        // `1 === "1"` is false, but `1 == "1"` is true.
+        assert!(!one.strict_equals(&one_string));
+        assert!(one.equals(&one_string)?);
+
+        assert!(one.strict_equals(&JSValue::new_number(&ctx, 1.0)));
+        assert!(JSValue::new_null(&ctx).equals(&JSValue::new_undefined(&ctx))?);
+        assert!(!JSValue::new_null(&ctx).strict_equals(&JSValue::new_undefined(&ctx)));
+
+        let nan = JSValue::new_number(&ctx, f64::NAN);
+        assert!(!nan.strict_equals(&nan));
+        assert!(!nan.equals(&nan)?);
+
+        // Exceptions thrown during the conversion are surfaced.
+        let throwing = evaluate_script(
+            &ctx,
+            "({ valueOf() { throw new Error('no') } })",
+            None,
+            "foo.js",
+            1,
+        )?;
+        let exception = throwing.equals(&one).err().unwrap();
+        assert_eq!(exception.to_string(), "Error: no");
+
+        Ok(())
+    }
+
+    #[test]
+    fn instance_of() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let class = evaluate_script(&ctx, "class Foo {}; Foo", None, "foo.js", 1)?.as_object()?;
+        let instance = evaluate_script(&ctx, "new Foo()", None, "foo.js", 1)?;
+        let plain = evaluate_script(&ctx, "({})", None, "foo.js", 1)?;
+
+        assert!(instance.is_instance_of(&class)?);
+        assert!(!plain.is_instance_of(&class)?);
+        assert!(!JSValue::new_number(&ctx, 1.0).is_instance_of(&class)?);
+
+        // Testing against something which isn't a constructor throws.
+        let not_a_constructor = evaluate_script(&ctx, "({})", None, "foo.js", 1)?.as_object()?;
+        assert!(instance.is_instance_of(&not_a_constructor).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn object() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let value = evaluate_script(&ctx, "({ foo: 'bar' })", None, "foo.js", 1)?;
+
+        let object = value.as_object()?;
+        assert_eq!(*object, value);
+        assert_eq!(object.get_property("foo").as_string()?, "bar");
+
+        // Primitive values are wrapped.
+        let object = JSValue::new_string(&ctx, "foo").as_object()?;
+        assert!(object.is_object());
+        assert_eq!(object.get_property("length").as_number()?, 3.);
+
+        let exception = JSValue::new_null(&ctx).as_object().err().unwrap();
+        assert!(exception.to_string().starts_with("TypeError"));
+        assert!(JSValue::new_undefined(&ctx).as_object().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn array() {
         let ctx = JSContext::default();
@@ -1202,6 +2804,100 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn function_errors_are_catchable() -> Result<(), JSException> {
+        use crate as javascriptcore;
+
+        let ctx = JSContext::default();
+
+        #[function_callback]
+        fn fail(
+            ctx: &JSContext,
+            _function: Option<&JSObject>,
+            _this_object: Option<&JSObject>,
+            arguments: &[JSValue],
+        ) -> Result<JSValue, JSException> {
+            match arguments.first() {
+                // Not an `Error`, but a plain string.
+                None => Err(JSValue::new_string(ctx, "plain").into()),
+                Some(message) => {
+                    Err(JSValue::from(ctx.new_type_error(message.as_string()?)?).into())
+                }
+            }
+        }
+
+        ctx.global_object()?
+            .set_property("fail", JSValue::new_function(&ctx, "fail", Some(fail)))?;
+
+        let result = evaluate_script(
+            &ctx,
+            "try { fail(); 'not thrown' } catch (e) { `${typeof e}:${e}` }",
+            None,
+            "test.js",
+            1,
+        )?;
+        assert_eq!(result.as_string()?, "string:plain");
+
+        let result = evaluate_script(
+            &ctx,
+            "try { fail('oops'); 'not thrown' } catch (e) { e instanceof TypeError && e.message }",
+            None,
+            "test.js",
+            1,
+        )?;
+        assert_eq!(result.as_string()?, "oops");
+
+        Ok(())
+    }
+
+    #[test]
+    fn debug() {
+        let ctx = JSContext::default();
+        let debug = |script: &str| {
+            let value = evaluate_script(&ctx, script, None, "test.js", 1).unwrap();
+            format!("{value:?}")
+        };
+
+        assert_eq!(debug("undefined"), "JSValue { undefined }");
+        assert_eq!(debug("null"), "JSValue { null }");
+        assert_eq!(debug("true"), "JSValue { true }");
+        assert_eq!(debug("1.5"), "JSValue { 1.5 }");
+        assert_eq!(debug("-0"), "JSValue { 0 }");
+        assert_eq!(debug("NaN"), "JSValue { NaN }");
+        assert_eq!(debug("10n"), "JSValue { 10n }");
+        assert_eq!(debug("'say \"hi\"'"), r#"JSValue { "say \"hi\"" }"#);
+        assert_eq!(debug("Symbol('tag')"), "JSValue { Symbol(tag) }");
+        assert_eq!(debug("Symbol()"), "JSValue { Symbol() }");
+        assert_eq!(debug("({ a: [1, 'b'] })"), r#"JSValue { {"a":[1,"b"]} }"#);
+        assert_eq!(debug("(() => {})"), "JSValue { [object] }");
+        assert_eq!(debug("var o = {}; o.o = o; o"), "JSValue { [object] }");
+
+        let exception = evaluate_script(&ctx, "throw 'oops'", None, "test.js", 1).unwrap_err();
+        assert_eq!(
+            format!("{exception:?}"),
+            r#"JSException { value: JSValue { "oops" } }"#
+        );
+    }
+
+    #[test]
+    fn truthiness() {
+        let ctx = JSContext::default();
+
+        for falsy in ["false", "0", "-0", "NaN", "0n", "''", "null", "undefined"] {
+            let v = evaluate_script(&ctx, falsy, None, "test.js", 1).unwrap();
+            assert!(!v.as_boolean(), "{falsy} is falsy");
+        }
+
+        for truthy in ["true", "1", "'0'", "({})", "[]", "(() => {})", "Symbol()"] {
+            let v = evaluate_script(&ctx, truthy, None, "test.js", 1).unwrap();
+            assert!(v.as_boolean(), "{truthy} is truthy");
+        }
+
+        // Only booleans are booleans without conversion.
+        assert_eq!(JSValue::new_string(&ctx, "").as_bool(), None);
+        assert_eq!(JSValue::new_boolean(&ctx, false).as_bool(), Some(false));
+    }
+
     #[test]
     fn json_boolean_true() {
         let ctx = JSContext::default();
@@ -1273,6 +2969,48 @@ mod tests {
         assert_eq!(s, "\"3\"");
     }
 
+    #[test]
+    fn deep_clone() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let original = evaluate_script(
+            &ctx,
+            "({ name: 'a', nested: { list: [1, { x: 2 }] }, f() {}, missing: undefined })",
+            None,
+            "test.js",
+            1,
+        )?;
+        let copy = original.deep_clone()?;
+
+        let object = original.as_object()?;
+        evaluate_script(
+            &ctx,
+            "this.nested.list[1].x = 3",
+            Some(&object),
+            "test.js",
+            1,
+        )?;
+        object.set_property("name", JSValue::new_string(&ctx, "b"))?;
+
+        assert_eq!(
+            copy.to_json_string(0)?,
+            r#"{"name":"a","nested":{"list":[1,{"x":2}]}}"#
+        );
+        assert!(!copy.as_object()?.has_property("f"));
+        assert!(!copy.as_object()?.has_property("missing"));
+        assert!(!original.strict_equals(&copy));
+
+        // Primitive values are copied as is.
+        let number = JSValue::new_number(&ctx, 1.5);
+        assert_eq!(number.deep_clone()?.as_number()?, 1.5);
+
+        // Values which can't be serialized can't be copied.
+        assert!(JSValue::new_undefined(&ctx).deep_clone().is_err());
+        let circular = evaluate_script(&ctx, "var o = {}; o.o = o; o", None, "test.js", 1)?;
+        assert!(circular.deep_clone().is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn json_failure() {
         let ctx = JSContext::default();
@@ -1280,4 +3018,39 @@ mod tests {
         let v = JSValue::new_from_json(&ctx, "3 +");
         assert!(v.is_none());
     }
+
+    #[test]
+    fn json_nested_object() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let json = r#"{"name":"foo","tags":["a","b"],"inner":{"n":3,"ok":true,"none":null}}"#;
+
+        let v = JSValue::new_from_json(&ctx, json).expect("value");
+        let inner = v.as_object()?.get_property("inner").as_object()?;
+        assert_eq!(inner.get_property("n").as_number()?, 3.);
+        assert!(inner.get_property("none").is_null());
+
+        assert_eq!(v.to_json_string(0)?, json);
+
+        let pretty = v.to_json_string(1)?.to_string();
+        assert!(pretty.starts_with("{\n \"name\": \"foo\",\n \"tags\": [\n  \"a\","));
+
+        // And back again.
+        let w = JSValue::new_from_json(&ctx, pretty).expect("value");
+        assert_eq!(w.to_json_string(0)?, json);
+
+        Ok(())
+    }
+
+    #[test]
+    fn json_unserializable() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+
+        let circular = evaluate_script(&ctx, "const o = {}; o.o = o; o", None, "foo.js", 1)?;
+        let exception = circular.to_json_string(0).unwrap_err();
+        assert_eq!(exception.name()?, "TypeError");
+
+        assert!(JSValue::new_undefined(&ctx).to_json_string(0).is_err());
+
+        Ok(())
+    }
 }
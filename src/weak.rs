@@ -0,0 +1,170 @@
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::{
+    class::{self, DeferredRelease},
+    sys, JSContext, JSContextGroup, JSObject,
+};
+use std::mem::ManuallyDrop;
+
+/// A weak reference to a [`JSObject`], which doesn't keep it alive.
+///
+/// Contrary to a [`Protected`](crate::Protected) object, the garbage
+/// collector can collect the referenced object, after which
+/// [`WeakJSObject::upgrade()`] returns `None`. Use it to associate Rust data
+/// with objects, e.g. in a cache, without preventing their collection.
+///
+/// The reference keeps the context group of the object alive, and can be
+/// upgraded in any context of that group.
+///
+/// This type is part of the [private API](crate#private-api), from
+/// `JSWeakPrivate.h`.
+///
+/// A `WeakJSObject` dropped by the finalizer of an object, as part of its
+/// [private data](crate::JSClass::new_object_with_private_data), can't be
+/// released right away, since JavaScriptCore can't be called from there: it
+/// is released, with its context group, the next time this crate uses a
+/// context on the same thread.
+///
+/// ```rust
+/// # use javascriptcore::{evaluate_script, JSContext, WeakJSObject};
+/// let ctx = JSContext::default();
+/// let object = evaluate_script(&ctx, "({ answer: 42 })", None, "foo.js", 1)
+///     .unwrap()
+///     .as_object()
+///     .unwrap();
+///
+/// let weak = WeakJSObject::new(&object);
+/// let object = weak.upgrade(&ctx).unwrap();
+///
+/// assert_eq!(object.get_property("answer").as_number().unwrap(), 42.);
+/// ```
+pub struct WeakJSObject {
+    raw: sys::JSWeakRef,
+    group: ManuallyDrop<JSContextGroup>,
+}
+
+impl WeakJSObject {
+    /// Creates a weak reference to `object`.
+    pub fn new(object: &JSObject) -> Self {
+        class::run_deferred_releases(object.value.ctx);
+
+        let group = ManuallyDrop::new(JSContextGroup {
+            raw: unsafe { sys::JSContextGroupRetain(sys::JSContextGetGroup(object.value.ctx)) },
+        });
+        let raw = unsafe { sys::JSWeakCreate(group.raw, object.raw) };
+
+        Self { raw, group }
+    }
+
+    /// Gets the referenced object, if it hasn't been garbage collected.
+    ///
+    /// `ctx` must be in the context group of the object, otherwise `None`
+    /// is returned.
+    pub fn upgrade(&self, ctx: &JSContext) -> Option<JSObject> {
+        if ctx.group().raw != self.group.raw {
+            return None;
+        }
+
+        let object = unsafe { sys::JSWeakGetObject(self.raw) };
+
+        if object.is_null() {
+            None
+        } else {
+            Some(unsafe { JSObject::from_raw(ctx.raw, object) })
+        }
+    }
+}
+
+impl Clone for WeakJSObject {
+    fn clone(&self) -> Self {
+        unsafe { sys::JSWeakRetain(self.group.raw, self.raw) };
+
+        Self {
+            raw: self.raw,
+            group: ManuallyDrop::new(JSContextGroup {
+                raw: unsafe { sys::JSContextGroupRetain(self.group.raw) },
+            }),
+        }
+    }
+}
+
+impl Drop for WeakJSObject {
+    fn drop(&mut self) {
+        // JavaScriptCore can't be called from a finalizer.
+        if class::is_finalizing() {
+            class::defer_release(DeferredRelease::Weak(self.group.raw, self.raw));
+
+            return;
+        }
+
+        unsafe {
+            sys::JSWeakRelease(self.group.raw, self.raw);
+            ManuallyDrop::drop(&mut self.group);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeakJSObject;
+    use crate::{evaluate_script, sys, JSContext, JSException};
+
+    #[test]
+    fn upgrade() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let weak = WeakJSObject::new(
+            &evaluate_script(&ctx, "globalThis.kept = { a: 1 }", None, "foo.js", 1)?.as_object()?,
+        );
+
+        // Still referenced by the global object.
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+
+        let object = weak.clone().upgrade(&ctx).unwrap();
+        assert_eq!(object.get_property("a").as_number()?, 1.);
+
+        // Another context group.
+        assert!(weak.upgrade(&JSContext::default()).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn upgrade_collected_objects() -> Result<(), JSException> {
+        let ctx = JSContext::default();
+        let weaks = {
+            let objects = evaluate_script(
+                &ctx,
+                "globalThis.objects = Array.from({ length: 100 }, (_, i) => ({ i }))",
+                None,
+                "foo.js",
+                1,
+            )?
+            .as_object()?;
+
+            (0..100)
+                .map(|i| {
+                    Ok(WeakJSObject::new(
+                        &objects.get_property_at_index(i).as_object()?,
+                    ))
+                })
+                .collect::<Result<Vec<_>, JSException>>()?
+        };
+
+        // Still referenced by the global object.
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+        assert!(weaks.iter().all(|weak| weak.upgrade(&ctx).is_some()));
+
+        evaluate_script(&ctx, "delete globalThis.objects", None, "foo.js", 1)?;
+        unsafe { sys::JSSynchronousGarbageCollectForDebugging(ctx.raw) };
+
+        // The garbage collector is conservative, so some objects may still be
+        // referenced from the stack, but not all of them.
+        assert!(weaks.iter().any(|weak| weak.upgrade(&ctx).is_none()));
+
+        Ok(())
+    }
+}